@@ -1,12 +1,255 @@
 use chrono::prelude::*;
 use chrono::{Datelike, Duration, Local, LocalResult, TimeZone, Utc};
 
-#[derive(Clone, PartialEq, Debug, Eq)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "DateTime<F>: serde::Serialize",
+        deserialize = "DateTime<F>: serde::Deserialize<'de>"
+    ))
+)]
 pub struct EasyTime<F: TimeZone> {
     pub value: i64,
     pub time_now: DateTime<F>,
 }
 
+/// The units an `EasyTime` value can be applied in.
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum TimeUnits {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Fortnights,
+    Months,
+    Quarters,
+    Years,
+    Decades,
+    Centuries,
+    Millenniums,
+}
+
+/// Which way `_from_now` methods move for the current `value`.
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum Direction {
+    /// `value` is positive: `_from_now` moves into the future.
+    Forward,
+    /// `value` is negative: `_from_now` moves into the past.
+    Backward,
+    /// `value` is zero: offsets leave `time_now` unchanged.
+    Zero,
+}
+
+#[derive(Clone, PartialEq, Debug, Eq)]
+pub enum EasyTimeError {
+    /// `value` cannot be applied in `unit` without overflowing.
+    ValueOverflow { value: i64, unit: TimeUnits },
+    /// The input string could not be parsed.
+    ParseError(String),
+    /// The named timezone is not known.
+    InvalidTimeZone(String),
+    /// The timestamp is outside the range chrono can represent.
+    TimestampOutOfRange(i64),
+    /// The local time does not exist in the timezone (e.g. a DST gap).
+    NonExistentLocalTime(NaiveDateTime),
+    /// The local time occurs twice in the timezone (e.g. a DST fall-back).
+    AmbiguousLocalTime(NaiveDateTime),
+    /// The duration cannot be expressed as requested.
+    InvalidDuration(String),
+    /// The date/time components do not form a valid date and time.
+    InvalidComponents(String),
+}
+
+impl std::fmt::Display for EasyTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EasyTimeError::ValueOverflow { value, unit } => {
+                write!(f, "value {} overflows when applied as {:?}", value, unit)
+            }
+            EasyTimeError::ParseError(reason) => write!(f, "parse error: {}", reason),
+            EasyTimeError::InvalidTimeZone(name) => write!(f, "invalid timezone: {}", name),
+            EasyTimeError::TimestampOutOfRange(ts) => {
+                write!(f, "timestamp {} is out of range", ts)
+            }
+            EasyTimeError::NonExistentLocalTime(naive) => {
+                write!(f, "local time {} does not exist in this timezone", naive)
+            }
+            EasyTimeError::AmbiguousLocalTime(naive) => {
+                write!(f, "local time {} is ambiguous in this timezone", naive)
+            }
+            EasyTimeError::InvalidDuration(reason) => write!(f, "invalid duration: {}", reason),
+            EasyTimeError::InvalidComponents(reason) => {
+                write!(f, "invalid date/time components: {}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EasyTimeError {}
+
+/// A `(start, end)` pair of instants.
+pub type Interval<F> = (DateTime<F>, DateTime<F>);
+
+/// A calendar-aware breakdown of the distance between two times.
+/// Every field is zero or negative when the other time comes first.
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Default)]
+pub struct TimeDiff {
+    pub years: i64,
+    pub months: i64,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub nanoseconds: i64,
+}
+
+/// Wording used by `EasyTime::to_relative_with_labels`.
+///
+/// Each unit holds `(singular, plural)` templates in which `{n}` is replaced
+/// by the count; `past` and `future` wrap the result in place of `{}`.
+#[derive(Clone, PartialEq, Debug, Eq)]
+pub struct RelativeLabels {
+    pub just_now: String,
+    pub past: String,
+    pub future: String,
+    pub seconds: (String, String),
+    pub minutes: (String, String),
+    pub hours: (String, String),
+    pub days: (String, String),
+    pub weeks: (String, String),
+    pub months: (String, String),
+    pub years: (String, String),
+}
+
+impl Default for RelativeLabels {
+    /// English labels: "just now", "3 days ago", "in 1 hour".
+    fn default() -> Self {
+        let unit = |name: &str| (format!("{{n}} {}", name), format!("{{n}} {}s", name));
+        Self {
+            just_now: "just now".to_string(),
+            past: "{} ago".to_string(),
+            future: "in {}".to_string(),
+            seconds: unit("second"),
+            minutes: unit("minute"),
+            hours: unit("hour"),
+            days: unit("day"),
+            weeks: unit("week"),
+            months: unit("month"),
+            years: unit("year"),
+        }
+    }
+}
+
+/// Broken-down local time using the C `struct tm` conventions.
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Default)]
+pub struct Tm {
+    /// Seconds after the minute, 0–60 (60 only for a leap second).
+    pub tm_sec: i32,
+    /// Minutes after the hour, 0–59.
+    pub tm_min: i32,
+    /// Hours since midnight, 0–23.
+    pub tm_hour: i32,
+    /// Day of the month, 1–31.
+    pub tm_mday: i32,
+    /// Months since January, 0–11.
+    pub tm_mon: i32,
+    /// Years since 1900.
+    pub tm_year: i32,
+    /// Days since Sunday, 0–6.
+    pub tm_wday: i32,
+    /// Days since January 1st, 0–365.
+    pub tm_yday: i32,
+}
+
+impl<F: TimeZone> From<EasyTime<F>> for DateTime<F> {
+    fn from(easy_time: EasyTime<F>) -> Self {
+        easy_time.time_now
+    }
+}
+
+// Equality and ordering compare `time_now` and then `value`. They are written
+// by hand so they don't require the timezone type itself (e.g. `Local`) to
+// implement the traits.
+impl<F: TimeZone> PartialEq for EasyTime<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.time_now == other.time_now && self.value == other.value
+    }
+}
+
+impl<F: TimeZone> Eq for EasyTime<F> {}
+
+impl<F: TimeZone> PartialOrd for EasyTime<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: TimeZone> Ord for EasyTime<F> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.time_now
+            .cmp(&other.time_now)
+            .then_with(|| self.value.cmp(&other.value))
+    }
+}
+
+impl<F: TimeZone> std::hash::Hash for EasyTime<F> {
+    /// Hashes the instant (not its timezone) and `value`, matching `Eq`.
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.time_now.timestamp().hash(state);
+        self.time_now.timestamp_subsec_nanos().hash(state);
+        self.value.hash(state);
+    }
+}
+
+impl<F: TimeZone> std::ops::Add<Duration> for EasyTime<F>
+where
+    F::Offset: std::fmt::Display,
+{
+    type Output = EasyTime<F>;
+
+    /// Shifts `time_now` forward by `duration`, keeping `value`; saturates on overflow.
+    fn add(self, duration: Duration) -> Self::Output {
+        let time_now = self.offset(duration);
+        EasyTime {
+            value: self.value,
+            time_now,
+        }
+    }
+}
+
+impl<F: TimeZone> std::ops::Sub<Duration> for EasyTime<F>
+where
+    F::Offset: std::fmt::Display,
+{
+    type Output = EasyTime<F>;
+
+    /// Shifts `time_now` backward by `duration`, keeping `value`; saturates on overflow.
+    fn sub(self, duration: Duration) -> Self::Output {
+        let time_now = self.offset_neg(duration);
+        EasyTime {
+            value: self.value,
+            time_now,
+        }
+    }
+}
+
+impl std::str::FromStr for EasyTime<Utc> {
+    type Err = EasyTimeError;
+
+    /// Parses RFC 3339, falling back to `%Y-%m-%d %H:%M:%S` taken as UTC.
+    /// The resulting `value` is 0.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EasyTime::parse_rfc3339(s, 0)
+            .or_else(|_| EasyTime::parse_with_format(s, "%Y-%m-%d %H:%M:%S", 0))
+    }
+}
+
 // ----------------------------------------------------------
 //           EasyTime<Local>: Constructors
 // ----------------------------------------------------------
@@ -24,6 +267,75 @@ impl EasyTime<Local> {
             time_now: time,
         }
     }
+
+    /// Parses `s` with the chrono format `fmt` as a local wall-clock time.
+    /// An ambiguous local time resolves to the earlier instant; a time in a
+    /// DST gap fails with `NonExistentLocalTime`.
+    pub fn parse_with_format_local(s: &str, fmt: &str, value: i64) -> Result<Self, EasyTimeError> {
+        let naive = NaiveDateTime::parse_from_str(s.trim(), fmt).map_err(|e| {
+            EasyTimeError::ParseError(format!("{:?} does not match {:?}: {}", s, fmt, e))
+        })?;
+        match Local.from_local_datetime(&naive) {
+            LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => {
+                Ok(Self::new_with_local(dt, value))
+            }
+            LocalResult::None => Err(EasyTimeError::NonExistentLocalTime(naive)),
+        }
+    }
+
+    /// `time_now`, or the current local time if `time_now` is already in the past.
+    pub fn clamp_into_future(&self) -> DateTime<Local> {
+        std::cmp::max(self.time_now, Local::now())
+    }
+
+    /// `time_now`, or the current local time if `time_now` is still in the future.
+    pub fn clamp_into_past(&self) -> DateTime<Local> {
+        std::cmp::min(self.time_now, Local::now())
+    }
+
+    /// Whether `time_now` is within `window` of the current instant, in either direction.
+    pub fn is_recent(&self, window: Duration) -> bool {
+        (self.time_now - Local::now()).abs() <= window
+    }
+
+    /// The first instant after `time_now` at which the local UTC offset changes
+    /// (a DST transition), found by scanning day by day and then bisecting to
+    /// the second. Returns `None` if the offset doesn't change within ~400 days,
+    /// e.g. in zones without DST.
+    pub fn next_dst_transition(&self) -> Option<DateTime<Local>> {
+        const SEARCH_DAYS: usize = 400;
+        let offset_at = |ts: i64| {
+            Local
+                .timestamp_opt(ts, 0)
+                .single()
+                .map(|dt| dt.offset().local_minus_utc())
+        };
+        let initial = offset_at(self.time_now.timestamp())?;
+        let mut lo = self.time_now.timestamp();
+        for _ in 0..SEARCH_DAYS {
+            let mut hi = lo + 86_400;
+            if offset_at(hi)? != initial {
+                while hi - lo > 1 {
+                    let mid = lo + (hi - lo) / 2;
+                    if offset_at(mid)? == initial {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                return Local.timestamp_opt(hi, 0).single();
+            }
+            lo = hi;
+        }
+        None
+    }
+
+    /// Whether the local UTC offset differs between `start` and `end`, i.e.
+    /// the range crosses a DST change. Only the endpoints are compared, so a
+    /// range spanning a full DST cycle reports `false`.
+    pub fn range_crosses_dst(start: DateTime<Local>, end: DateTime<Local>) -> bool {
+        start.offset().local_minus_utc() != end.offset().local_minus_utc()
+    }
 }
 
 // ----------------------------------------------------------
@@ -36,6 +348,337 @@ impl EasyTime<Utc> {
             time_now: Utc::now(),
         }
     }
+
+    /// `time_now`, or the current UTC time if `time_now` is already in the past.
+    pub fn clamp_into_future(&self) -> DateTime<Utc> {
+        std::cmp::max(self.time_now, Utc::now())
+    }
+
+    /// `time_now`, or the current UTC time if `time_now` is still in the future.
+    pub fn clamp_into_past(&self) -> DateTime<Utc> {
+        std::cmp::min(self.time_now, Utc::now())
+    }
+
+    /// Whether `time_now` is within `window` of the current instant, in either direction.
+    pub fn is_recent(&self, window: Duration) -> bool {
+        (self.time_now - Utc::now()).abs() <= window
+    }
+
+    /// The Unix epoch, 1970-01-01T00:00:00Z, with a value of 0.
+    pub fn epoch() -> Self {
+        Self::from_time(DateTime::UNIX_EPOCH)
+    }
+
+    /// An instance at the earliest `DateTime<Utc>` chrono can represent,
+    /// e.g. as the starting point of a `max` fold.
+    pub fn min_utc(value: i64) -> Self {
+        Self::new_with_time(value, DateTime::<Utc>::MIN_UTC)
+    }
+
+    /// An instance at the latest `DateTime<Utc>` chrono can represent,
+    /// e.g. as the starting point of a `min` fold.
+    pub fn max_utc(value: i64) -> Self {
+        Self::new_with_time(value, DateTime::<Utc>::MAX_UTC)
+    }
+
+    /// Midnight UTC on Rata Die day `n` (0001-01-01 is day 1), or `None` if out of range.
+    pub fn from_rata_die(n: i64) -> Option<Self> {
+        let days = i32::try_from(n).ok()?;
+        let date = NaiveDate::from_num_days_from_ce_opt(days)?;
+        Some(Self::from_time(date.and_time(NaiveTime::MIN).and_utc()))
+    }
+
+    /// Builds an instance from Unix epoch seconds.
+    pub fn from_timestamp(ts: i64) -> Result<Self, EasyTimeError> {
+        DateTime::from_timestamp(ts, 0)
+            .map(Self::from_time)
+            .ok_or(EasyTimeError::TimestampOutOfRange(ts))
+    }
+
+    /// Builds an instance from Unix epoch seconds given as a string, e.g. `"1696161600"`.
+    pub fn from_timestamp_str(s: &str) -> Result<Self, EasyTimeError> {
+        let ts = s
+            .trim()
+            .parse::<i64>()
+            .map_err(|e| EasyTimeError::ParseError(format!("invalid timestamp {:?}: {}", s, e)))?;
+        Self::from_timestamp(ts)
+    }
+
+    /// Parses an ordinal date such as `2023-274` to midnight UTC.
+    pub fn from_ordinal_date(s: &str) -> Result<Self, EasyTimeError> {
+        NaiveDate::parse_from_str(s.trim(), "%Y-%j")
+            .map(|date| Self::from_time(date.and_time(NaiveTime::MIN).and_utc()))
+            .map_err(|e| EasyTimeError::ParseError(format!("invalid ordinal date {:?}: {}", s, e)))
+    }
+
+    /// Parses `s` with the chrono format `fmt`, taking the result as UTC.
+    pub fn parse_with_format(s: &str, fmt: &str, value: i64) -> Result<Self, EasyTimeError> {
+        NaiveDateTime::parse_from_str(s.trim(), fmt)
+            .map(|naive| Self::new_with_time(value, naive.and_utc()))
+            .map_err(|e| {
+                EasyTimeError::ParseError(format!("{:?} does not match {:?}: {}", s, fmt, e))
+            })
+    }
+
+    /// Parses an RFC 3339 string such as `2023-10-01T14:30:00+02:00`,
+    /// normalizing it to UTC.
+    pub fn parse_rfc3339(s: &str, value: i64) -> Result<Self, EasyTimeError> {
+        DateTime::parse_from_rfc3339(s.trim())
+            .map(|dt| Self::new_with_time(value, dt.with_timezone(&Utc)))
+            .map_err(|e| EasyTimeError::ParseError(format!("invalid RFC 3339 {:?}: {}", s, e)))
+    }
+
+    /// Rebuilds a UTC instance from C `struct tm` fields. `tm_wday` and
+    /// `tm_yday` are ignored; a `tm_sec` of 60 is read as a leap second.
+    pub fn from_tm(tm: &Tm) -> Result<Self, EasyTimeError> {
+        let invalid = || EasyTimeError::InvalidComponents(format!("{:?}", tm));
+        let field = |value: i32| u32::try_from(value).map_err(|_| invalid());
+        let year = tm.tm_year.checked_add(1900).ok_or_else(invalid)?;
+        let date = NaiveDate::from_ymd_opt(year, field(tm.tm_mon)? + 1, field(tm.tm_mday)?)
+            .ok_or_else(invalid)?;
+        let (sec, nano) = match field(tm.tm_sec)? {
+            60 => (59, 1_000_000_000),
+            sec => (sec, 0),
+        };
+        let time = NaiveTime::from_hms_nano_opt(field(tm.tm_hour)?, field(tm.tm_min)?, sec, nano)
+            .ok_or_else(invalid)?;
+        Ok(Self::from_time(date.and_time(time).and_utc()))
+    }
+
+    /// Best-effort parser for human input. Formats are tried in order:
+    /// RFC 3339, RFC 2822, ISO 8601 without an offset (`2023-10-01T12:00:00`,
+    /// `2023-10-01 12:00:00`, `2023-10-01`), US `MM/DD/YYYY` and finally
+    /// European `DD/MM/YYYY`, each optionally followed by `HH:MM:SS`.
+    ///
+    /// Because US dates are tried first, an ambiguous input such as
+    /// `01/02/2023` is read as January 2nd; the European reading is only
+    /// used when the US one is invalid (e.g. `25/12/2023`). Inputs without
+    /// an offset are taken as UTC and date-only inputs as midnight. The
+    /// result carries `value`.
+    pub fn parse_flexible(s: &str, value: i64) -> Result<Self, EasyTimeError> {
+        const DATE_TIME_FORMATS: [&str; 4] = [
+            "%Y-%m-%dT%H:%M:%S%.f",
+            "%Y-%m-%d %H:%M:%S%.f",
+            "%m/%d/%Y %H:%M:%S",
+            "%d/%m/%Y %H:%M:%S",
+        ];
+        const DATE_FORMATS: [&str; 3] = ["%Y-%m-%d", "%m/%d/%Y", "%d/%m/%Y"];
+
+        let s = s.trim();
+        if let Ok(dt) = DateTime::parse_from_rfc3339(s).or_else(|_| DateTime::parse_from_rfc2822(s))
+        {
+            return Ok(Self::new_with_time(value, dt.with_timezone(&Utc)));
+        }
+        let naive = DATE_TIME_FORMATS
+            .iter()
+            .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())
+            .or_else(|| {
+                DATE_FORMATS
+                    .iter()
+                    .find_map(|fmt| NaiveDate::parse_from_str(s, fmt).ok())
+                    .map(|date| date.and_time(NaiveTime::MIN))
+            });
+        match naive {
+            Some(naive) => Ok(Self::new_with_time(value, naive.and_utc())),
+            None => Err(EasyTimeError::ParseError(format!(
+                "unrecognized date/time format: {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+// ----------------------------------------------------------
+//           Timezone-Independent Helpers
+// ----------------------------------------------------------
+// These don't depend on a timezone. They live on `EasyTime<Utc>` so they can
+// be called as `EasyTime::name(..)` without a type annotation.
+impl EasyTime<Utc> {
+    /// Formats `value` of `unit` as an ISO 8601 duration, e.g. `P3W` or `PT90M`.
+    /// Decades and longer are expressed in years; negative values get a leading `-`.
+    pub fn iso8601_duration(value: i64, unit: TimeUnits) -> String {
+        let n = value.unsigned_abs() as u128;
+        let body = match unit {
+            TimeUnits::Nanoseconds => format!("T{}S", Self::iso8601_seconds(n)),
+            TimeUnits::Microseconds => format!("T{}S", Self::iso8601_seconds(n * 1_000)),
+            TimeUnits::Milliseconds => format!("T{}S", Self::iso8601_seconds(n * 1_000_000)),
+            TimeUnits::Seconds => format!("T{}S", n),
+            TimeUnits::Minutes => format!("T{}M", n),
+            TimeUnits::Hours => format!("T{}H", n),
+            TimeUnits::Days => format!("{}D", n),
+            TimeUnits::Weeks => format!("{}W", n),
+            TimeUnits::Fortnights => format!("{}W", n * 2),
+            TimeUnits::Months => format!("{}M", n),
+            TimeUnits::Quarters => format!("{}M", n * 3),
+            TimeUnits::Years => format!("{}Y", n),
+            TimeUnits::Decades => format!("{}Y", n * 10),
+            TimeUnits::Centuries => format!("{}Y", n * 100),
+            TimeUnits::Millenniums => format!("{}Y", n * 1000),
+        };
+        let sign = if value < 0 { "-" } else { "" };
+        format!("{}P{}", sign, body)
+    }
+
+    /// Renders `nanos` as decimal seconds without trailing zeros, e.g. `1.5`.
+    fn iso8601_seconds(nanos: u128) -> String {
+        let (whole, frac) = (nanos / 1_000_000_000, nanos % 1_000_000_000);
+        if frac == 0 {
+            whole.to_string()
+        } else {
+            let frac = format!("{:09}", frac);
+            format!("{}.{}", whole, frac.trim_end_matches('0'))
+        }
+    }
+
+    /// Breaks `duration` into its non-zero days, hours, minutes and seconds,
+    /// largest first, e.g. 26 hours becomes `[(1, "days"), (2, "hours")]`.
+    /// Sub-second precision is dropped and negative durations give negative counts.
+    pub fn duration_parts_labeled(duration: Duration) -> Vec<(i64, &'static str)> {
+        const PARTS: [(i64, &str); 4] = [
+            (86_400, "days"),
+            (3_600, "hours"),
+            (60, "minutes"),
+            (1, "seconds"),
+        ];
+        let mut remaining = duration.num_seconds();
+        PARTS
+            .iter()
+            .filter_map(|&(size, label)| {
+                let count = remaining / size;
+                remaining %= size;
+                (count != 0).then_some((count, label))
+            })
+            .collect()
+    }
+
+    /// Splits seconds since midnight into `(hour, minute, second)`, or `None`
+    /// if `secs` is a full day or more.
+    pub fn seconds_to_clock(secs: u32) -> Option<(u32, u32, u32)> {
+        (secs < 86_400).then_some((secs / 3_600, secs % 3_600 / 60, secs % 60))
+    }
+
+    /// Whether `naive` exists as a wall-clock time in `tz`. Ambiguous times
+    /// (e.g. during a DST fall-back) count as valid; DST gaps do not.
+    pub fn is_valid_in_zone<T: TimeZone>(naive: NaiveDateTime, tz: &T) -> bool {
+        !matches!(tz.from_local_datetime(&naive), LocalResult::None)
+    }
+
+    /// The Unix timestamp of `naive` read as a wall-clock time in `tz`.
+    /// Fails if the time falls in a DST gap or is ambiguous in `tz`.
+    pub fn epoch_if_in_zone<T: TimeZone>(
+        naive: NaiveDateTime,
+        tz: &T,
+    ) -> Result<i64, EasyTimeError> {
+        match tz.from_local_datetime(&naive) {
+            LocalResult::Single(dt) => Ok(dt.timestamp()),
+            LocalResult::Ambiguous(_, _) => Err(EasyTimeError::AmbiguousLocalTime(naive)),
+            LocalResult::None => Err(EasyTimeError::NonExistentLocalTime(naive)),
+        }
+    }
+
+    /// Combines several non-negative components into one ISO 8601 duration,
+    /// e.g. `[(1, Days), (2, Hours)]` becomes `P1DT2H`.
+    ///
+    /// ISO 8601 only allows the week designator on its own (`PnW`), so weeks
+    /// combined with any other unit are rejected.
+    pub fn iso8601_duration_parts(parts: &[(i64, TimeUnits)]) -> Result<String, EasyTimeError> {
+        const DESIGNATORS: [char; 7] = ['Y', 'M', 'W', 'D', 'H', 'M', 'S'];
+
+        let is_weeks = |unit: &TimeUnits| matches!(unit, TimeUnits::Weeks | TimeUnits::Fortnights);
+        let has_weeks = parts.iter().any(|(_, unit)| is_weeks(unit));
+        if has_weeks && parts.iter().any(|(_, unit)| !is_weeks(unit)) {
+            return Err(EasyTimeError::InvalidDuration(
+                "weeks cannot be combined with other units in ISO 8601".to_string(),
+            ));
+        }
+
+        // Seconds are accumulated in nanoseconds so sub-second units can be folded in.
+        let mut totals = [0u128; 7];
+        for &(value, unit) in parts {
+            if value < 0 {
+                return Err(EasyTimeError::InvalidDuration(format!(
+                    "negative component {} {:?}",
+                    value, unit
+                )));
+            }
+            let (index, factor) = match unit {
+                TimeUnits::Years => (0, 1),
+                TimeUnits::Decades => (0, 10),
+                TimeUnits::Centuries => (0, 100),
+                TimeUnits::Millenniums => (0, 1000),
+                TimeUnits::Months => (1, 1),
+                TimeUnits::Quarters => (1, 3),
+                TimeUnits::Weeks => (2, 1),
+                TimeUnits::Fortnights => (2, 2),
+                TimeUnits::Days => (3, 1),
+                TimeUnits::Hours => (4, 1),
+                TimeUnits::Minutes => (5, 1),
+                TimeUnits::Seconds => (6, 1_000_000_000),
+                TimeUnits::Milliseconds => (6, 1_000_000),
+                TimeUnits::Microseconds => (6, 1_000),
+                TimeUnits::Nanoseconds => (6, 1),
+            };
+            totals[index] += value as u128 * factor;
+        }
+
+        let render = |range: std::ops::Range<usize>| -> String {
+            range
+                .filter(|&i| totals[i] > 0)
+                .map(|i| match i {
+                    6 => format!("{}S", Self::iso8601_seconds(totals[i])),
+                    _ => format!("{}{}", totals[i], DESIGNATORS[i]),
+                })
+                .collect()
+        };
+        let (date, time) = (render(0..4), render(4..7));
+        Ok(match (date.is_empty(), time.is_empty()) {
+            (true, true) => "PT0S".to_string(),
+            (_, true) => format!("P{}", date),
+            _ => format!("P{}T{}", date, time),
+        })
+    }
+}
+
+// ----------------------------------------------------------
+//           EasyTime<FixedOffset>: Constructors
+// ----------------------------------------------------------
+impl EasyTime<FixedOffset> {
+    /// Attaches an offset given in minutes east of UTC to a local `naive`
+    /// date-time. Returns `None` unless the offset is strictly within ±1440 minutes.
+    pub fn from_naive_and_offset_minutes(
+        naive: NaiveDateTime,
+        offset_minutes: i32,
+    ) -> Option<Self> {
+        if offset_minutes.unsigned_abs() >= 1440 {
+            return None;
+        }
+        let offset = FixedOffset::east_opt(offset_minutes * 60)?;
+        let time = offset.from_local_datetime(&naive).single()?;
+        Some(Self::from_time(time))
+    }
+}
+
+// ----------------------------------------------------------
+//           EasyTime<Tz>: IANA Timezones (chrono-tz)
+// ----------------------------------------------------------
+#[cfg(feature = "chrono-tz")]
+impl EasyTime<chrono_tz::Tz> {
+    /// Returns the UTC timestamp together with the IANA zone name.
+    pub fn to_epoch_and_zone(&self) -> (i64, String) {
+        (
+            self.time_now.timestamp(),
+            self.time_now.timezone().name().to_string(),
+        )
+    }
+
+    /// Rebuilds an instance from a UTC timestamp and an IANA zone name.
+    pub fn from_epoch_and_zone(ts: i64, zone: &str) -> Result<Self, EasyTimeError> {
+        let tz: chrono_tz::Tz = zone
+            .parse()
+            .map_err(|_| EasyTimeError::InvalidTimeZone(zone.to_string()))?;
+        let utc = DateTime::from_timestamp(ts, 0).ok_or(EasyTimeError::TimestampOutOfRange(ts))?;
+        Ok(Self::from_time(utc.with_timezone(&tz)))
+    }
 }
 
 // ----------------------------------------------------------
@@ -71,6 +714,46 @@ where
         self.time_now = time;
     }
 
+    /// Sets `value` only if it can be applied in `unit` (in either direction)
+    /// without overflowing the offset arithmetic or leaving chrono's year range.
+    pub fn set_value_checked(&mut self, value: i64, unit: TimeUnits) -> Result<(), EasyTimeError> {
+        if !self.value_fits_unit(value, unit) {
+            return Err(EasyTimeError::ValueOverflow { value, unit });
+        }
+        self.value = value;
+        Ok(())
+    }
+
+    /// Adds `other` to `value`, saturating on overflow.
+    pub fn add_value(&mut self, other: i64) {
+        self.value = self.value.saturating_add(other);
+    }
+
+    /// Subtracts `other` from `value`, saturating on overflow.
+    pub fn sub_value(&mut self, other: i64) {
+        self.value = self.value.saturating_sub(other);
+    }
+
+    /// Makes `value` non-negative (saturating at `i64::MAX`).
+    pub fn abs_value(&mut self) {
+        self.value = self.value.saturating_abs();
+    }
+
+    /// Flips the sign of `value` (saturating at `i64::MAX`).
+    pub fn negate_value(&mut self) {
+        self.value = self.value.saturating_neg();
+    }
+
+    /// The direction `_from_now` methods move for the current `value`;
+    /// `_ago` methods move the opposite way.
+    pub fn direction(&self) -> Direction {
+        match self.value.signum() {
+            1 => Direction::Forward,
+            -1 => Direction::Backward,
+            _ => Direction::Zero,
+        }
+    }
+
     pub fn get_value(&self) -> i64 {
         self.value
     }
@@ -79,6 +762,11 @@ where
         self.time_now.clone()
     }
 
+    /// Consumes the wrapper and returns `time_now`.
+    pub fn into_inner(self) -> DateTime<F> {
+        self.time_now
+    }
+
     // ------------------------------------------------------------------
     //                      Internal Helpers
     // ------------------------------------------------------------------
@@ -110,66 +798,273 @@ where
         }
     }
 
-    /// Add `duration` to `time_now`.
+    /// Whether `value` in `unit` can be added to or subtracted from `time_now`
+    /// by the offset methods without panicking or saturating.
+    fn value_fits_unit(&self, value: i64, unit: TimeUnits) -> bool {
+        if let Some(duration) = Self::unit_duration(value, unit) {
+            return self.time_now.clone().checked_add_signed(duration).is_some()
+                && self.time_now.clone().checked_sub_signed(duration).is_some();
+        }
+        let months_per_unit = match unit {
+            TimeUnits::Months => 1,
+            TimeUnits::Quarters => 3,
+            TimeUnits::Years => 12,
+            TimeUnits::Decades => 120,
+            TimeUnits::Centuries => 1_200,
+            TimeUnits::Millenniums => 12_000,
+            // Fixed units whose duration itself overflows
+            _ => return false,
+        };
+        // An ambiguous target still resolves (to its earlier instant).
+        let resolves = |result: Result<DateTime<F>, EasyTimeError>| {
+            matches!(result, Ok(_) | Err(EasyTimeError::AmbiguousLocalTime(_)))
+        };
+        if months_per_unit < 12 {
+            // Month-based getters shift by `value` months as an `i32`.
+            let months = i32::try_from(value)
+                .ok()
+                .and_then(|value| value.checked_mul(months_per_unit));
+            return months.is_some_and(|months| {
+                months.checked_neg().is_some_and(|negated| {
+                    resolves(self.try_add_months(months)) && resolves(self.try_add_months(negated))
+                })
+            });
+        }
+        let naive = self.time_now.naive_local();
+        let (min_year, max_year) = Self::supported_year_range();
+        let in_range = |target: Option<i64>| {
+            target.is_some_and(|y| (min_year as i64..=max_year as i64).contains(&y))
+        };
+        // Resolve strictly so a target pushed past chrono's range by the offset
+        // is rejected rather than saturated as `try_add_years` would.
+        let fits = |years: i64| {
+            in_range((naive.year() as i64).checked_add(years))
+                && resolves(
+                    self.build_datetime_from_naive_strict(Self::add_years_naive(naive, years)),
+                )
+        };
+        value
+            .checked_mul(months_per_unit as i64 / 12)
+            .is_some_and(|years| {
+                years
+                    .checked_neg()
+                    .is_some_and(|negated| fits(years) && fits(negated))
+            })
+    }
+
+    /// `value` of a fixed-length `unit` as a `Duration`, or `None` for calendar
+    /// units and for values too large for a `Duration`.
+    fn unit_duration(value: i64, unit: TimeUnits) -> Option<Duration> {
+        match unit {
+            TimeUnits::Nanoseconds => Some(Duration::nanoseconds(value)),
+            TimeUnits::Microseconds => Some(Duration::microseconds(value)),
+            TimeUnits::Milliseconds => Duration::try_milliseconds(value),
+            TimeUnits::Seconds => Duration::try_seconds(value),
+            TimeUnits::Minutes => Duration::try_minutes(value),
+            TimeUnits::Hours => Duration::try_hours(value),
+            TimeUnits::Days => Duration::try_days(value),
+            TimeUnits::Weeks => Duration::try_weeks(value),
+            TimeUnits::Fortnights => value.checked_mul(2).and_then(Duration::try_weeks),
+            TimeUnits::Months
+            | TimeUnits::Quarters
+            | TimeUnits::Years
+            | TimeUnits::Decades
+            | TimeUnits::Centuries
+            | TimeUnits::Millenniums => None,
+        }
+    }
+
+    /// Add `duration` to `time_now`, saturating at the representable range.
     fn offset(&self, duration: Duration) -> DateTime<F> {
-        self.time_now.clone() + duration
+        Self::offset_saturating(&self.time_now, duration)
     }
 
-    /// Subtract `duration` from `time_now`.
+    /// Subtract `duration` from `time_now`, saturating at the representable range.
     fn offset_neg(&self, duration: Duration) -> DateTime<F> {
-        self.time_now.clone() - duration
+        Self::offset_saturating(&self.time_now, -duration)
     }
 
     /// Tries to build a `DateTime<F>` from a naive date-time.
     /// Uses `.unwrap()` in ambiguous cases by picking the first match, and panics on invalid.
-    fn from_naive_local(&self, naive: chrono::NaiveDateTime) -> DateTime<F> {
+    fn build_datetime_from_naive(&self, naive: chrono::NaiveDateTime) -> DateTime<F> {
+        self.try_build_datetime_from_naive(naive)
+            .expect("Invalid or non-existent local time.")
+    }
+
+    /// Like `build_datetime_from_naive`, but reports non-existent local times as an error.
+    fn try_build_datetime_from_naive(
+        &self,
+        naive: chrono::NaiveDateTime,
+    ) -> Result<DateTime<F>, EasyTimeError> {
+        match self.time_now.timezone().from_local_datetime(&naive) {
+            LocalResult::Single(dt) => Ok(dt),
+            LocalResult::Ambiguous(a, _b) => Ok(a),
+            LocalResult::None => Err(EasyTimeError::NonExistentLocalTime(naive)),
+        }
+    }
+
+    /// Builds a `DateTime<F>` from a naive date-time, reporting both DST gaps
+    /// and DST overlaps as errors instead of picking an instant.
+    fn build_datetime_from_naive_strict(
+        &self,
+        naive: chrono::NaiveDateTime,
+    ) -> Result<DateTime<F>, EasyTimeError> {
         match self.time_now.timezone().from_local_datetime(&naive) {
-            LocalResult::Single(dt) => dt,
-            LocalResult::Ambiguous(a, _b) => a,
-            LocalResult::None => panic!("Invalid or non-existent local time."),
+            LocalResult::Single(dt) => Ok(dt),
+            LocalResult::Ambiguous(_, _) => Err(EasyTimeError::AmbiguousLocalTime(naive)),
+            LocalResult::None => Err(EasyTimeError::NonExistentLocalTime(naive)),
+        }
+    }
+
+    /// Unwraps a strict result the way the panicking offset methods always have:
+    /// an ambiguous local time resolves to its earlier instant, anything else panics.
+    fn or_earliest(&self, result: Result<DateTime<F>, EasyTimeError>) -> DateTime<F> {
+        match result {
+            Ok(dt) => dt,
+            Err(EasyTimeError::AmbiguousLocalTime(naive)) => self.build_datetime_from_naive(naive),
+            Err(err) => panic!("{}", err),
         }
     }
 
     // ------------------------------------------------------------------
-    //           Simple Offsets: seconds, minutes, hours, days
+    //     Simple Offsets: sub-second, seconds, minutes, hours, days
     // ------------------------------------------------------------------
+    // Every fixed-length offset (nanoseconds through fortnights) saturates at
+    // the earliest/latest representable instant instead of panicking.
+    /// Adds `duration` to `time`, clamping to the earliest/latest representable
+    /// instant instead of panicking on overflow.
+    pub fn offset_saturating(time: &DateTime<F>, duration: Duration) -> DateTime<F> {
+        time.clone()
+            .checked_add_signed(duration)
+            .unwrap_or_else(|| {
+                let bound = if duration < Duration::zero() {
+                    DateTime::<Utc>::MIN_UTC
+                } else {
+                    DateTime::<Utc>::MAX_UTC
+                };
+                bound.with_timezone(&time.timezone())
+            })
+    }
+
+    pub fn nanoseconds_from_now(&self) -> DateTime<F> {
+        self.offset(Self::saturating_duration(
+            self.value,
+            TimeUnits::Nanoseconds,
+        ))
+    }
+
+    pub fn nanoseconds_ago(&self) -> DateTime<F> {
+        self.offset_neg(Self::saturating_duration(
+            self.value,
+            TimeUnits::Nanoseconds,
+        ))
+    }
+
+    pub fn microseconds_from_now(&self) -> DateTime<F> {
+        self.offset(Self::saturating_duration(
+            self.value,
+            TimeUnits::Microseconds,
+        ))
+    }
+
+    pub fn microseconds_ago(&self) -> DateTime<F> {
+        self.offset_neg(Self::saturating_duration(
+            self.value,
+            TimeUnits::Microseconds,
+        ))
+    }
+
+    pub fn milliseconds_from_now(&self) -> DateTime<F> {
+        self.offset(Self::saturating_duration(
+            self.value,
+            TimeUnits::Milliseconds,
+        ))
+    }
+
+    pub fn milliseconds_ago(&self) -> DateTime<F> {
+        self.offset_neg(Self::saturating_duration(
+            self.value,
+            TimeUnits::Milliseconds,
+        ))
+    }
+
     pub fn seconds_from_now(&self) -> DateTime<F> {
-        self.offset(Duration::seconds(self.value))
+        self.offset(Self::saturating_duration(self.value, TimeUnits::Seconds))
     }
 
     pub fn seconds_ago(&self) -> DateTime<F> {
-        self.offset_neg(Duration::seconds(self.value))
+        self.offset_neg(Self::saturating_duration(self.value, TimeUnits::Seconds))
     }
 
     pub fn minutes_from_now(&self) -> DateTime<F> {
-        self.offset(Duration::minutes(self.value))
+        self.offset(Self::saturating_duration(self.value, TimeUnits::Minutes))
     }
 
     pub fn minutes_ago(&self) -> DateTime<F> {
-        self.offset_neg(Duration::minutes(self.value))
+        self.offset_neg(Self::saturating_duration(self.value, TimeUnits::Minutes))
     }
 
     pub fn hours_from_now(&self) -> DateTime<F> {
-        self.offset(Duration::hours(self.value))
+        self.offset(Self::saturating_duration(self.value, TimeUnits::Hours))
     }
 
     pub fn hours_ago(&self) -> DateTime<F> {
-        self.offset_neg(Duration::hours(self.value))
+        self.offset_neg(Self::saturating_duration(self.value, TimeUnits::Hours))
     }
 
     pub fn days_from_now(&self) -> DateTime<F> {
-        self.offset(Duration::days(self.value))
+        self.offset(Self::saturating_duration(self.value, TimeUnits::Days))
     }
 
     pub fn days_ago(&self) -> DateTime<F> {
-        self.offset_neg(Duration::days(self.value))
+        self.offset_neg(Self::saturating_duration(self.value, TimeUnits::Days))
+    }
+
+    pub fn weeks_from_now(&self) -> DateTime<F> {
+        self.offset(Self::saturating_duration(self.value, TimeUnits::Weeks))
+    }
+
+    pub fn weeks_ago(&self) -> DateTime<F> {
+        self.offset_neg(Self::saturating_duration(self.value, TimeUnits::Weeks))
+    }
+
+    pub fn fortnights_from_now(&self) -> DateTime<F> {
+        self.offset(Self::saturating_duration(self.value, TimeUnits::Fortnights))
+    }
+
+    pub fn fortnights_ago(&self) -> DateTime<F> {
+        self.offset_neg(Self::saturating_duration(self.value, TimeUnits::Fortnights))
+    }
+
+    /// `value` of a fixed-length `unit` as a `Duration`, saturating at the
+    /// largest representable duration instead of overflowing.
+    fn saturating_duration(value: i64, unit: TimeUnits) -> Duration {
+        Self::unit_duration(value, unit).unwrap_or(if value < 0 {
+            Duration::MIN
+        } else {
+            Duration::MAX
+        })
     }
 
     // ------------------------------------------------------------------
     //               Month-Based Offset (custom logic)
     // ------------------------------------------------------------------
     fn add_months(&self, months: i32) -> DateTime<F> {
-        let naive = self.time_now.naive_local();
+        self.or_earliest(self.try_add_months(months))
+    }
+
+    fn try_add_months(&self, months: i32) -> Result<DateTime<F>, EasyTimeError> {
+        let target_naive_dt = Self::add_months_naive(self.time_now.naive_local(), months).ok_or(
+            EasyTimeError::ValueOverflow {
+                value: months as i64,
+                unit: TimeUnits::Months,
+            },
+        )?;
+        self.build_datetime_from_naive_strict(target_naive_dt)
+    }
+
+    /// Shifts a naive date-time by `months`, clamping the day to the target month.
+    fn add_months_naive(naive: NaiveDateTime, months: i32) -> Option<NaiveDateTime> {
         let (year, month, day) = (naive.year(), naive.month() as i32, naive.day());
 
         // Calculate target year and month
@@ -189,11 +1084,8 @@ where
         let target_day = std::cmp::min(day, days_in_target);
 
         let target_date =
-            chrono::NaiveDate::from_ymd_opt(target_year, target_month as u32, target_day)
-                .expect("Invalid date after adding months");
-
-        let target_naive_dt = target_date.and_time(naive.time());
-        self.from_naive_local(target_naive_dt)
+            chrono::NaiveDate::from_ymd_opt(target_year, target_month as u32, target_day)?;
+        Some(target_date.and_time(naive.time()))
     }
 
     pub fn months_from_now(&self) -> DateTime<F> {
@@ -204,68 +1096,264 @@ where
         self.add_months(-(self.value as i32))
     }
 
+    pub fn quarters_from_now(&self) -> DateTime<F> {
+        self.add_months((self.value as i32).saturating_mul(3))
+    }
+
+    pub fn quarters_ago(&self) -> DateTime<F> {
+        self.add_months((self.value as i32).saturating_mul(-3))
+    }
+
+    /// Like `months_from_now`, but returns an error when the target local time
+    /// falls in a DST gap or overlap instead of panicking or picking an instant.
+    pub fn try_months_from_now(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_months(self.value as i32)
+    }
+
+    pub fn try_months_ago(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_months(-(self.value as i32))
+    }
+
+    pub fn try_quarters_from_now(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_months((self.value as i32).saturating_mul(3))
+    }
+
+    pub fn try_quarters_ago(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_months((self.value as i32).saturating_mul(-3))
+    }
+
     // ------------------------------------------------------------------
     //               Year-Based Offsets (custom logic)
     // ------------------------------------------------------------------
-    fn add_years(&self, years: i32) -> DateTime<F> {
-        let naive = self.time_now.naive_local();
-        let (year, month, day) = (naive.year() + years, naive.month(), naive.day());
+    /// Shifts the calendar year by `years`, keeping month/day (clamped for Feb 29).
+    /// The target year saturates at chrono's supported range rather than panicking,
+    /// and at the very edge of that range falls back to the boundary instant.
+    fn add_years(&self, years: i64) -> DateTime<F> {
+        self.or_earliest(self.try_add_years(years))
+    }
+
+    fn try_add_years(&self, years: i64) -> Result<DateTime<F>, EasyTimeError> {
+        let target_naive_dt = Self::add_years_naive(self.time_now.naive_local(), years);
+        match self.build_datetime_from_naive_strict(target_naive_dt) {
+            // At the edge of the year range the offset can push the instant past
+            // chrono's limits; saturate to the boundary instant instead.
+            Err(EasyTimeError::NonExistentLocalTime(_)) if Self::at_year_limit(target_naive_dt) => {
+                let bound = if years < 0 {
+                    DateTime::<Utc>::MIN_UTC
+                } else {
+                    DateTime::<Utc>::MAX_UTC
+                };
+                Ok(bound.with_timezone(&self.time_now.timezone()))
+            }
+            result => result,
+        }
+    }
+
+    /// Shifts a naive date-time by `years`, clamping Feb 29 and saturating the
+    /// year at chrono's supported range.
+    fn add_years_naive(naive: NaiveDateTime, years: i64) -> NaiveDateTime {
+        let (min_year, max_year) = Self::supported_year_range();
+        let year = (naive.year() as i64)
+            .saturating_add(years)
+            .clamp(min_year as i64, max_year as i64) as i32;
+        let (month, day) = (naive.month(), naive.day());
 
         let days_in_target = Self::days_in_month(year, month);
         let target_day = std::cmp::min(day, days_in_target);
 
         let target_date = chrono::NaiveDate::from_ymd_opt(year, month, target_day)
             .expect("Invalid date after adding years");
+        target_date.and_time(naive.time())
+    }
+
+    /// Whether `naive` is in the first or last year chrono can represent.
+    fn at_year_limit(naive: NaiveDateTime) -> bool {
+        let (min_year, max_year) = Self::supported_year_range();
+        naive.year() == min_year || naive.year() == max_year
+    }
 
-        let target_naive_dt = target_date.and_time(naive.time());
-        self.from_naive_local(target_naive_dt)
+    /// The first and last calendar years chrono can represent.
+    fn supported_year_range() -> (i32, i32) {
+        (NaiveDate::MIN.year(), NaiveDate::MAX.year())
     }
 
     pub fn years_from_now(&self) -> DateTime<F> {
-        self.add_years(self.value as i32)
+        self.add_years(self.value)
     }
 
     pub fn years_ago(&self) -> DateTime<F> {
-        self.add_years(-(self.value as i32))
+        self.add_years(self.value.saturating_neg())
     }
 
     pub fn decades_from_now(&self) -> DateTime<F> {
-        self.add_years(self.value as i32 * 10)
+        self.add_years(self.value.saturating_mul(10))
     }
 
     pub fn decades_ago(&self) -> DateTime<F> {
-        self.add_years(-(self.value as i32) * 10)
+        self.add_years(self.value.saturating_mul(10).saturating_neg())
     }
 
     pub fn centuries_from_now(&self) -> DateTime<F> {
-        self.add_years(self.value as i32 * 100)
+        self.add_years(self.value.saturating_mul(100))
     }
 
     pub fn centuries_ago(&self) -> DateTime<F> {
-        self.add_years(-(self.value as i32) * 100)
+        self.add_years(self.value.saturating_mul(100).saturating_neg())
     }
 
     pub fn millenniums_from_now(&self) -> DateTime<F> {
-        self.add_years(self.value as i32 * 1000)
+        self.add_years(self.value.saturating_mul(1000))
     }
 
     pub fn millenniums_ago(&self) -> DateTime<F> {
-        self.add_years(-(self.value as i32) * 1000)
+        self.add_years(self.value.saturating_mul(1000).saturating_neg())
     }
 
-    // ------------------------------------------------------------------
-    //          Formatting Methods
-    // ------------------------------------------------------------------
-    /// Internal helper to format the current time with an optional timezone suffix.
-    fn format_with(&self, format_str: &str, show_tz: bool) -> String {
-        let base = self.time_now.format(format_str).to_string();
-        if show_tz {
-            format!("{} {}", base, self.time_now.offset())
-        } else {
-            base
-        }
+    /// Like `years_from_now`, but returns an error when the target local time
+    /// falls in a DST gap or overlap instead of panicking or picking an instant.
+    pub fn try_years_from_now(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_years(self.value)
     }
 
+    pub fn try_years_ago(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_years(self.value.saturating_neg())
+    }
+
+    pub fn try_decades_from_now(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_years(self.value.saturating_mul(10))
+    }
+
+    pub fn try_decades_ago(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_years(self.value.saturating_mul(10).saturating_neg())
+    }
+
+    pub fn try_centuries_from_now(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_years(self.value.saturating_mul(100))
+    }
+
+    pub fn try_centuries_ago(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_years(self.value.saturating_mul(100).saturating_neg())
+    }
+
+    pub fn try_millenniums_from_now(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_years(self.value.saturating_mul(1000))
+    }
+
+    pub fn try_millenniums_ago(&self) -> Result<DateTime<F>, EasyTimeError> {
+        self.try_add_years(self.value.saturating_mul(1000).saturating_neg())
+    }
+
+    // ------------------------------------------------------------------
+    //               Offsets by TimeUnits
+    // ------------------------------------------------------------------
+    /// Applies `value` of `unit` to `time_now`, calendar-aware for months and longer.
+    fn apply_time_unit_forward(&self, value: i64, unit: TimeUnits) -> DateTime<F> {
+        match unit {
+            TimeUnits::Nanoseconds => self.offset(Self::saturating_duration(value, unit)),
+            TimeUnits::Microseconds => self.offset(Self::saturating_duration(value, unit)),
+            TimeUnits::Milliseconds => self.offset(Self::saturating_duration(value, unit)),
+            TimeUnits::Seconds => self.offset(Self::saturating_duration(value, unit)),
+            TimeUnits::Minutes => self.offset(Self::saturating_duration(value, unit)),
+            TimeUnits::Hours => self.offset(Self::saturating_duration(value, unit)),
+            TimeUnits::Days => self.offset(Self::saturating_duration(value, unit)),
+            TimeUnits::Weeks => self.offset(Self::saturating_duration(value, unit)),
+            TimeUnits::Fortnights => self.offset(Self::saturating_duration(value, unit)),
+            TimeUnits::Months => self.add_months(value as i32),
+            TimeUnits::Quarters => self.add_months((value as i32).saturating_mul(3)),
+            TimeUnits::Years => self.add_years(value),
+            TimeUnits::Decades => self.add_years(value.saturating_mul(10)),
+            TimeUnits::Centuries => self.add_years(value.saturating_mul(100)),
+            TimeUnits::Millenniums => self.add_years(value.saturating_mul(1000)),
+        }
+    }
+
+    /// Applies `value` of `unit` backwards from `time_now`; the mirror of `apply_time_unit_forward`.
+    fn apply_time_unit_backward(&self, value: i64, unit: TimeUnits) -> DateTime<F> {
+        match unit {
+            TimeUnits::Nanoseconds => self.offset_neg(Self::saturating_duration(value, unit)),
+            TimeUnits::Microseconds => self.offset_neg(Self::saturating_duration(value, unit)),
+            TimeUnits::Milliseconds => self.offset_neg(Self::saturating_duration(value, unit)),
+            TimeUnits::Seconds => self.offset_neg(Self::saturating_duration(value, unit)),
+            TimeUnits::Minutes => self.offset_neg(Self::saturating_duration(value, unit)),
+            TimeUnits::Hours => self.offset_neg(Self::saturating_duration(value, unit)),
+            TimeUnits::Days => self.offset_neg(Self::saturating_duration(value, unit)),
+            TimeUnits::Weeks => self.offset_neg(Self::saturating_duration(value, unit)),
+            TimeUnits::Fortnights => self.offset_neg(Self::saturating_duration(value, unit)),
+            TimeUnits::Months => self.add_months(-(value as i32)),
+            TimeUnits::Quarters => self.add_months((value as i32).saturating_mul(-3)),
+            TimeUnits::Years => self.add_years(value.saturating_neg()),
+            TimeUnits::Decades => self.add_years(value.saturating_mul(10).saturating_neg()),
+            TimeUnits::Centuries => self.add_years(value.saturating_mul(100).saturating_neg()),
+            TimeUnits::Millenniums => self.add_years(value.saturating_mul(1000).saturating_neg()),
+        }
+    }
+
+    /// `value` of `unit` after `time_now`, e.g. `in_future(TimeUnits::Weeks)`.
+    pub fn in_future(&self, unit: TimeUnits) -> DateTime<F> {
+        self.apply_time_unit_forward(self.value, unit)
+    }
+
+    /// `value` of `unit` before `time_now`, e.g. `in_past(TimeUnits::Weeks)`.
+    pub fn in_past(&self, unit: TimeUnits) -> DateTime<F> {
+        self.apply_time_unit_backward(self.value, unit)
+    }
+
+    /// The length of one `unit`, or `None` for calendar units whose length varies.
+    fn fixed_unit_duration(unit: TimeUnits) -> Option<Duration> {
+        match unit {
+            TimeUnits::Nanoseconds => Some(Duration::nanoseconds(1)),
+            TimeUnits::Microseconds => Some(Duration::microseconds(1)),
+            TimeUnits::Milliseconds => Some(Duration::milliseconds(1)),
+            TimeUnits::Seconds => Some(Duration::seconds(1)),
+            TimeUnits::Minutes => Some(Duration::minutes(1)),
+            TimeUnits::Hours => Some(Duration::hours(1)),
+            TimeUnits::Days => Some(Duration::days(1)),
+            TimeUnits::Weeks => Some(Duration::weeks(1)),
+            TimeUnits::Fortnights => Some(Duration::weeks(2)),
+            TimeUnits::Months
+            | TimeUnits::Quarters
+            | TimeUnits::Years
+            | TimeUnits::Decades
+            | TimeUnits::Centuries
+            | TimeUnits::Millenniums => None,
+        }
+    }
+
+    fn total_nanos(duration: Duration) -> i128 {
+        duration.num_seconds() as i128 * 1_000_000_000 + duration.subsec_nanos() as i128
+    }
+
+    /// Advances `time_now` by as many whole `unit`s as fit in `total`, returning
+    /// the new time and the leftover duration.
+    ///
+    /// Panics if `unit` is a calendar unit (months and longer).
+    pub fn step_with_remainder(&self, total: Duration, unit: TimeUnits) -> (DateTime<F>, Duration) {
+        let step = Self::fixed_unit_duration(unit)
+            .expect("step_with_remainder requires a fixed-length unit");
+        let remainder =
+            Duration::nanoseconds((Self::total_nanos(total) % Self::total_nanos(step)) as i64);
+        (self.offset(total - remainder), remainder)
+    }
+
+    /// Moves `time_now` forward by `value` of `unit` in place; `self.value` is left untouched.
+    pub fn shift_by_units(&mut self, value: i64, unit: TimeUnits) {
+        self.time_now = self.apply_time_unit_forward(value, unit);
+    }
+
+    // ------------------------------------------------------------------
+    //          Formatting Methods
+    // ------------------------------------------------------------------
+    /// Internal helper to format the current time with an optional timezone suffix.
+    fn format_with(&self, format_str: &str, show_tz: bool) -> String {
+        let base = self.time_now.format(format_str).to_string();
+        if show_tz {
+            format!("{} {}", base, self.time_now.offset())
+        } else {
+            base
+        }
+    }
+
+    #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
         self.format_with("%Y-%m-%d %H:%M:%S", false)
     }
@@ -287,21 +1375,197 @@ where
         self.format_with(format_str, true)
     }
 
+    /// Formats `time_now` and appends the offset normalized to `+HH:MM`,
+    /// regardless of how the timezone `F` displays its own offset.
+    pub fn to_string_with_format_offset_colon(&self, format_str: &str) -> String {
+        let offset_secs = self.time_now.offset().fix().local_minus_utc();
+        let sign = if offset_secs < 0 { '-' } else { '+' };
+        let abs = offset_secs.abs();
+        format!(
+            "{} {}{:02}:{:02}",
+            self.time_now.format(format_str),
+            sign,
+            abs / 3600,
+            (abs % 3600) / 60
+        )
+    }
+
+    /// Formats every item with the same strftime string, parsing it only once.
+    pub fn format_all(items: &[EasyTime<F>], format_str: &str) -> Vec<String> {
+        let parsed: Vec<chrono::format::Item> =
+            chrono::format::StrftimeItems::new(format_str).collect();
+        items
+            .iter()
+            .map(|item| item.time_now.format_with_items(parsed.iter()).to_string())
+            .collect()
+    }
+
     // ------------------------------------------------------------------
     //           Other Utilities
     // ------------------------------------------------------------------
+    /// Four-digit 24-hour local time, e.g. `1430`.
+    pub fn to_military_time(&self) -> String {
+        self.time_now.format("%H%M").to_string()
+    }
+
+    /// Four-digit 24-hour time converted to UTC with a `Z` suffix, e.g. `1430Z`.
+    pub fn to_military_time_zulu(&self) -> String {
+        self.time_now
+            .with_timezone(&Utc)
+            .format("%H%MZ")
+            .to_string()
+    }
+
+    /// A filesystem-safe timestamp such as `20231001_143005_042`
+    /// (date, time, then zero-padded milliseconds).
+    pub fn to_filename_timestamp(&self) -> String {
+        self.time_now.format("%Y%m%d_%H%M%S_%3f").to_string()
+    }
+
+    /// Formats `time_now` with month and weekday names in `locale`,
+    /// e.g. `"%d %B %Y"` gives `"14 juillet 2023"` in `fr_FR`.
+    #[cfg(feature = "unstable-locales")]
+    pub fn format_localized(&self, fmt: &str, locale: chrono::Locale) -> String {
+        self.time_now.format_localized(fmt, locale).to_string()
+    }
+
+    /// The abbreviated weekday name in `locale`, e.g. `"Mo"` for Monday in `de_DE`.
+    #[cfg(feature = "unstable-locales")]
+    pub fn weekday_abbr_localized(&self, locale: chrono::Locale) -> String {
+        self.format_localized("%a", locale)
+    }
+
+    /// Best-effort short zone name via `%Z`, e.g. `"UTC"` or `"EST"`.
+    /// Zones without names (`Local`, `FixedOffset`) return a numeric offset such as `"+02:00"`.
+    pub fn tz_abbreviation(&self) -> String {
+        self.time_now.format("%Z").to_string()
+    }
+
     pub fn to_timestamp(&self) -> i64 {
         self.time_now.timestamp()
     }
 
+    /// Time elapsed since the Unix epoch as a `std::time::Duration`.
+    /// Fails with `TimestampOutOfRange` for instants before the epoch.
+    pub fn unix_duration(&self) -> Result<std::time::Duration, EasyTimeError> {
+        let ts = self.time_now.timestamp();
+        let secs = u64::try_from(ts).map_err(|_| EasyTimeError::TimestampOutOfRange(ts))?;
+        Ok(std::time::Duration::new(
+            secs,
+            self.time_now.timestamp_subsec_nanos(),
+        ))
+    }
+
     pub fn to_date(&self) -> String {
         self.time_now.format("%Y-%m-%d").to_string()
     }
 
+    /// The local ISO date followed by its zone, `Z` for a zero offset and
+    /// `+HH:MM` otherwise, e.g. `2023-10-01Z` or `2023-10-01+02:00`.
+    pub fn to_iso_date_with_zone(&self) -> String {
+        if self.time_now.offset().fix().local_minus_utc() == 0 {
+            self.time_now.format("%Y-%m-%dZ").to_string()
+        } else {
+            self.time_now.format("%Y-%m-%d%:z").to_string()
+        }
+    }
+
     pub fn to_time(&self) -> String {
         self.time_now.format("%H:%M:%S").to_string()
     }
 
+    /// The local wall-clock date and time, without a timezone.
+    pub fn to_naive(&self) -> NaiveDateTime {
+        self.time_now.naive_local()
+    }
+
+    /// The UTC date and time, without a timezone.
+    pub fn to_naive_utc(&self) -> NaiveDateTime {
+        self.time_now.naive_utc()
+    }
+
+    /// The local `(year, month, day)` components.
+    pub fn date_parts(&self) -> (i32, u32, u32) {
+        let naive = self.time_now.naive_local();
+        (naive.year(), naive.month(), naive.day())
+    }
+
+    /// The local `(hour, minute, second)` components.
+    pub fn time_parts(&self) -> (u32, u32, u32) {
+        let naive = self.time_now.naive_local();
+        (naive.hour(), naive.minute(), naive.second())
+    }
+
+    /// The `(year, month, day)` components of the instant projected to UTC.
+    pub fn date_parts_utc(&self) -> (i32, u32, u32) {
+        let naive = self.time_now.naive_utc();
+        (naive.year(), naive.month(), naive.day())
+    }
+
+    /// The `(hour, minute, second)` components of the instant projected to UTC.
+    pub fn time_parts_utc(&self) -> (u32, u32, u32) {
+        let naive = self.time_now.naive_utc();
+        (naive.hour(), naive.minute(), naive.second())
+    }
+
+    /// The ordinal date, e.g. `2023-274`.
+    pub fn to_ordinal_date(&self) -> String {
+        self.time_now.format("%Y-%j").to_string()
+    }
+
+    /// The ISO-style week of the month: weeks run Monday–Sunday and each week
+    /// belongs to the month containing its Thursday, so week 1 is the week
+    /// holding the month's first Thursday. Days before that week belong to the
+    /// last week of the previous month and return that week's number (4 or 5).
+    pub fn iso_week_of_month(&self) -> u32 {
+        let date = self.time_now.naive_local().date();
+        let weekday = date.weekday().num_days_from_monday() as i64;
+        let thursday = date + Duration::days(3 - weekday);
+        (thursday.day() - 1) / 7 + 1
+    }
+
+    /// The Rata Die day number, counting 0001-01-01 as day 1.
+    pub fn rata_die(&self) -> i64 {
+        self.time_now.naive_local().date().num_days_from_ce() as i64
+    }
+
+    /// A dense month index, `year * 12 + (month - 1)`, for bucketing by month.
+    pub fn months_since_year_zero(&self) -> i64 {
+        let date = self.time_now.naive_local().date();
+        date.year() as i64 * 12 + date.month0() as i64
+    }
+
+    /// `(ISO week-year, ISO week number, ISO weekday 1–7 with Monday = 1)`.
+    pub fn iso_week_tuple(&self) -> (i32, u32, u32) {
+        let date = self.time_now.naive_local().date();
+        let week = date.iso_week();
+        (
+            week.year(),
+            week.week(),
+            date.weekday().number_from_monday(),
+        )
+    }
+
+    /// The local time broken down into C `struct tm` fields.
+    pub fn to_tm(&self) -> Tm {
+        let naive = self.time_now.naive_local();
+        let leap_second = if naive.nanosecond() >= 1_000_000_000 {
+            1
+        } else {
+            0
+        };
+        Tm {
+            tm_sec: (naive.second() + leap_second) as i32,
+            tm_min: naive.minute() as i32,
+            tm_hour: naive.hour() as i32,
+            tm_mday: naive.day() as i32,
+            tm_mon: naive.month0() as i32,
+            tm_year: naive.year() - 1900,
+            tm_wday: naive.weekday().num_days_from_sunday() as i32,
+            tm_yday: naive.ordinal0() as i32,
+        }
+    }
+
     pub fn to_date_time(&self) -> String {
         self.time_now.format("%Y-%m-%d %H:%M:%S").to_string()
     }
@@ -313,4 +1577,873 @@ where
             self.time_now.offset()
         )
     }
+
+    // ------------------------------------------------------------------
+    //           Differences
+    // ------------------------------------------------------------------
+    /// Whether both refer to the same instant. Unlike `==`, `value` is ignored.
+    pub fn same_time(&self, other: &EasyTime<F>) -> bool {
+        self.time_now == other.time_now
+    }
+
+    /// Whether both fall in the same calendar quarter of the same year, on
+    /// each one's local calendar.
+    pub fn is_same_quarter(&self, other: &EasyTime<F>) -> bool {
+        let quarter = |time: &DateTime<F>| (time.year(), time.month0() / 3);
+        quarter(&self.time_now) == quarter(&other.time_now)
+    }
+
+    /// Whole days of elapsed time from `time_now` to `other` (24-hour periods).
+    pub fn days_between(&self, other: &EasyTime<F>) -> i64 {
+        (other.time_now.clone() - self.time_now.clone()).num_days()
+    }
+
+    /// Calendar days from `time_now` to `other`, ignoring the time of day.
+    pub fn calendar_days_between(&self, other: &EasyTime<F>) -> i64 {
+        let from = self.time_now.naive_local().date().num_days_from_ce() as i64;
+        let to = other.time_now.naive_local().date().num_days_from_ce() as i64;
+        to - from
+    }
+
+    /// Breaks the distance from `time_now` to `other` into whole years and
+    /// months (stepped on the calendar, so February is never over- or
+    /// under-counted) plus the remaining days, hours, minutes, seconds and
+    /// nanoseconds, all measured on the local wall clock.
+    pub fn diff(&self, other: &EasyTime<F>) -> TimeDiff {
+        let from = self.time_now.naive_local();
+        let to = other.time_now.naive_local();
+        let (start, end, sign) = if from <= to {
+            (from, to, 1)
+        } else {
+            (to, from, -1)
+        };
+
+        let month_index = |naive: NaiveDateTime| naive.year() as i64 * 12 + naive.month0() as i64;
+        let mut months = month_index(end) - month_index(start);
+        let mut anchor =
+            Self::add_months_naive(start, months as i32).expect("Invalid month offset");
+        if anchor > end {
+            months -= 1;
+            anchor = Self::add_months_naive(start, months as i32).expect("Invalid month offset");
+        }
+
+        let rest = end - anchor;
+        TimeDiff {
+            years: sign * (months / 12),
+            months: sign * (months % 12),
+            days: sign * rest.num_days(),
+            hours: sign * (rest.num_hours() % 24),
+            minutes: sign * (rest.num_minutes() % 60),
+            seconds: sign * (rest.num_seconds() % 60),
+            nanoseconds: sign * rest.subsec_nanos() as i64,
+        }
+    }
+
+    /// The signed number of whole `unit`s from `time_now` to `other`. Fixed
+    /// units use elapsed time; months and longer use calendar months as in
+    /// `diff`, where a day missing from the target month clamps to its last
+    /// day. `months_between` and `years_between` follow the same rule.
+    pub fn span_in(&self, other: &EasyTime<F>, unit: TimeUnits) -> i64 {
+        if let Some(step) = Self::fixed_unit_duration(unit) {
+            let elapsed = other.time_now.clone() - self.time_now.clone();
+            return (Self::total_nanos(elapsed) / Self::total_nanos(step)) as i64;
+        }
+        let diff = self.diff(other);
+        let months = diff.years * 12 + diff.months;
+        let months_per_unit = match unit {
+            TimeUnits::Quarters => 3,
+            TimeUnits::Years => 12,
+            TimeUnits::Decades => 120,
+            TimeUnits::Centuries => 1_200,
+            TimeUnits::Millenniums => 12_000,
+            _ => 1,
+        };
+        months / months_per_unit
+    }
+
+    /// Signed count of full calendar months from `time_now` to `other`; the
+    /// same as `span_in(.., TimeUnits::Months)`. A month counts once its day of
+    /// month is reached, clamped to the end of shorter months, so Jan 15 to
+    /// Mar 10 is 1 month and Jan 31 to Feb 28 is also 1.
+    pub fn months_between(&self, other: DateTime<F>) -> i64 {
+        self.span_in(&Self::from_time(other), TimeUnits::Months)
+    }
+
+    /// Signed count of full years from `time_now` to `other`; the same as
+    /// `span_in(.., TimeUnits::Years)`. A year counts once the anniversary is
+    /// reached, and an anniversary on Feb 29 falls on Feb 28 in non-leap years.
+    pub fn years_between(&self, other: DateTime<F>) -> i64 {
+        self.span_in(&Self::from_time(other), TimeUnits::Years)
+    }
+
+    /// Counts the local calendar dates from `time_now` through `end`, inclusive,
+    /// for which `pred` returns `true`. Returns 0 if `end` is on an earlier date.
+    pub fn count_days_where<G: Fn(NaiveDate) -> bool>(&self, end: DateTime<F>, pred: G) -> i64 {
+        let first = self.time_now.naive_local().date();
+        let last = end.naive_local().date();
+        first
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .filter(|date| pred(*date))
+            .count() as i64
+    }
+
+    // ------------------------------------------------------------------
+    //           Intervals
+    // ------------------------------------------------------------------
+    /// The overlap of two half-open `[start, end)` intervals, or `None` if
+    /// they are disjoint or only touch at an endpoint.
+    pub fn intersection(a: Interval<F>, b: Interval<F>) -> Option<Interval<F>> {
+        let start = std::cmp::max(a.0, b.0);
+        let end = std::cmp::min(a.1, b.1);
+        if start < end {
+            Some((start, end))
+        } else {
+            None
+        }
+    }
+
+    /// Merges two intervals into one span if they overlap or touch.
+    /// If they are disjoint, returns the gap between them as `Err`.
+    pub fn merge(a: Interval<F>, b: Interval<F>) -> Result<Interval<F>, Interval<F>> {
+        let (first, second) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+        if second.0 <= first.1 {
+            Ok((first.0, std::cmp::max(first.1, second.1)))
+        } else {
+            Err((first.1, second.0))
+        }
+    }
+
+    /// The instant a fraction `t` of the way from `a` to `b`. `t` is clamped
+    /// to `0.0..=1.0`, so this never extrapolates past either end.
+    pub fn lerp(a: DateTime<F>, b: DateTime<F>, t: f64) -> DateTime<F> {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let span = b - a.clone();
+        let step = match span.num_nanoseconds() {
+            Some(nanos) => Duration::nanoseconds((nanos as f64 * t).round() as i64),
+            None => Duration::milliseconds((span.num_milliseconds() as f64 * t).round() as i64),
+        };
+        a + step
+    }
+
+    // ------------------------------------------------------------------
+    //           Relative Formatting
+    // ------------------------------------------------------------------
+    /// Splits a non-negative number of seconds into a count of the largest
+    /// unit that fits, using 30-day months and 365-day years.
+    fn relative_count(secs: i64) -> (i64, &'static str) {
+        const UNITS: [(i64, &str); 6] = [
+            (365 * 86_400, "year"),
+            (30 * 86_400, "month"),
+            (7 * 86_400, "week"),
+            (86_400, "day"),
+            (3_600, "hour"),
+            (60, "minute"),
+        ];
+        UNITS
+            .iter()
+            .find(|(size, _)| secs >= *size)
+            .map(|(size, name)| (secs / size, *name))
+            .unwrap_or((secs, "second"))
+    }
+
+    /// Describes `time_now` relative to `reference` using natural tense:
+    /// "yesterday" or "tomorrow" for the adjacent local calendar date, "last
+    /// week", "next month" and so on for a single unit, otherwise "3 days ago"
+    /// / "in 2 hours".
+    pub fn to_relative_tense(&self, reference: DateTime<F>) -> String {
+        let days = (self.time_now.naive_local().date() - reference.naive_local().date()).num_days();
+        match days {
+            -1 => return "yesterday".to_string(),
+            1 => return "tomorrow".to_string(),
+            _ => {}
+        }
+        let secs = (self.time_now.clone() - reference.clone()).num_seconds();
+        let (count, unit) = Self::relative_count(secs.abs());
+        if count == 1 && matches!(unit, "week" | "month" | "year") {
+            return if secs < 0 {
+                format!("last {}", unit)
+            } else {
+                format!("next {}", unit)
+            };
+        }
+        self.humanize(reference)
+    }
+
+    /// Describes `time_now` relative to `reference` in the largest fitting
+    /// unit, e.g. "5 minutes ago" or "in 3 months". Anything under a minute
+    /// is "just now".
+    pub fn humanize(&self, reference: DateTime<F>) -> String {
+        self.to_relative_with_labels(reference, &RelativeLabels::default())
+    }
+
+    /// Like `humanize`, but with the wording taken from `labels`, e.g. for
+    /// translations.
+    pub fn to_relative_with_labels(
+        &self,
+        reference: DateTime<F>,
+        labels: &RelativeLabels,
+    ) -> String {
+        let secs = (self.time_now.clone() - reference).num_seconds();
+        if secs.abs() < 60 {
+            return labels.just_now.clone();
+        }
+        let (count, unit) = Self::relative_count(secs.abs());
+        let (one, other) = match unit {
+            "year" => &labels.years,
+            "month" => &labels.months,
+            "week" => &labels.weeks,
+            "day" => &labels.days,
+            "hour" => &labels.hours,
+            "minute" => &labels.minutes,
+            _ => &labels.seconds,
+        };
+        let template = if count == 1 { one } else { other };
+        let amount = template.replace("{n}", &count.to_string());
+        let affix = if secs < 0 {
+            &labels.past
+        } else {
+            &labels.future
+        };
+        affix.replace("{}", &amount)
+    }
+
+    // ------------------------------------------------------------------
+    //           Business Calendar
+    // ------------------------------------------------------------------
+    /// The local day of the week.
+    pub fn weekday(&self) -> Weekday {
+        self.time_now.weekday()
+    }
+
+    /// True on a local Saturday or Sunday.
+    pub fn is_weekend(&self) -> bool {
+        Self::is_weekend_day(self.weekday())
+    }
+
+    /// True on Monday–Friday when the local hour is in `start_hour..end_hour`.
+    pub fn is_within_business_hours(&self, start_hour: u32, end_hour: u32) -> bool {
+        let naive = self.time_now.naive_local();
+        !Self::is_weekend_day(naive.weekday()) && (start_hour..end_hour).contains(&naive.hour())
+    }
+
+    /// Returns `time_now` unchanged on a weekday, otherwise the following
+    /// Monday at the same time of day.
+    pub fn next_business_day(&self) -> DateTime<F> {
+        self.business_day_after(0)
+    }
+
+    /// The first weekday strictly after the current date, at the same time of day.
+    pub fn next_business_day_strict(&self) -> DateTime<F> {
+        self.business_day_after(1)
+    }
+
+    /// Moves `n` weekdays forward (or backward for negative `n`), skipping
+    /// Saturdays and Sundays and keeping the time of day. A weekend start
+    /// counts from the adjacent weekday, so Saturday plus one is Monday.
+    pub fn add_business_days(&self, n: i64) -> DateTime<F> {
+        if n == 0 {
+            return self.time_now.clone();
+        }
+        let naive = self.time_now.naive_local();
+        let forward = n > 0;
+        let shift = |date: NaiveDate, days: u64| {
+            if forward {
+                date.checked_add_days(chrono::Days::new(days))
+            } else {
+                date.checked_sub_days(chrono::Days::new(days))
+            }
+            .expect("Invalid date after adding business days")
+        };
+
+        // Start from a weekday so whole weeks keep the same weekday.
+        let mut date = naive.date();
+        while Self::is_weekend_day(date.weekday()) {
+            date = if forward {
+                date.pred_opt()
+            } else {
+                date.succ_opt()
+            }
+            .expect("Invalid date while skipping a weekend");
+        }
+        let remaining = n.unsigned_abs();
+        date = shift(date, remaining / 5 * 7);
+        for _ in 0..remaining % 5 {
+            date = shift(date, 1);
+            while Self::is_weekend_day(date.weekday()) {
+                date = shift(date, 1);
+            }
+        }
+        self.build_datetime_from_naive(date.and_time(naive.time()))
+    }
+
+    fn is_weekend_day(day: Weekday) -> bool {
+        matches!(day, Weekday::Sat | Weekday::Sun)
+    }
+
+    /// The first weekday at least `min_days` after the current local date.
+    fn business_day_after(&self, min_days: u64) -> DateTime<F> {
+        let naive = self.time_now.naive_local();
+        let mut date = naive.date() + chrono::Days::new(min_days);
+        while Self::is_weekend_day(date.weekday()) {
+            date = date.succ_opt().expect("Invalid date after advancing a day");
+        }
+        if date == naive.date() {
+            return self.time_now.clone();
+        }
+        self.build_datetime_from_naive(date.and_time(naive.time()))
+    }
+
+    // ------------------------------------------------------------------
+    //           Day Boundaries
+    // ------------------------------------------------------------------
+    /// Midnight at the start of the local date. In zones where a DST change
+    /// skips midnight, this is the first instant after the gap instead.
+    pub fn start_of_day(&self) -> DateTime<F> {
+        self.start_of_date(self.time_now.naive_local().date())
+    }
+
+    /// The last nanosecond of the local date (`23:59:59.999999999`). If that
+    /// local time occurs twice, the later instant is used.
+    pub fn end_of_day(&self) -> DateTime<F> {
+        self.end_of_date(self.time_now.naive_local().date())
+    }
+
+    /// The first existing instant of `date`, normally its midnight.
+    fn start_of_date(&self, date: NaiveDate) -> DateTime<F> {
+        self.first_valid_from(date.and_time(NaiveTime::MIN))
+    }
+
+    /// Resolves `naive`, moving forward minute by minute out of a DST gap.
+    fn first_valid_from(&self, naive: NaiveDateTime) -> DateTime<F> {
+        let first_valid = (0..=24 * 60)
+            .map(|minutes| naive + Duration::minutes(minutes))
+            .find(|naive| self.try_build_datetime_from_naive(*naive).is_ok())
+            .unwrap_or(naive);
+        self.build_datetime_from_naive(first_valid)
+    }
+
+    /// The last instant of `date`, preferring the later one if it occurs twice.
+    fn end_of_date(&self, date: NaiveDate) -> DateTime<F> {
+        let naive = Self::last_nanosecond_of(date);
+        match self.time_now.timezone().from_local_datetime(&naive) {
+            LocalResult::Ambiguous(_, latest) => latest,
+            _ => self.build_datetime_from_naive(naive),
+        }
+    }
+
+    // ------------------------------------------------------------------
+    //           Month Boundaries
+    // ------------------------------------------------------------------
+    /// Midnight on the first day of the local month.
+    pub fn start_of_month(&self) -> DateTime<F> {
+        let date = self.time_now.naive_local().date();
+        self.start_of_date(date.with_day(1).expect("Invalid first day of month"))
+    }
+
+    /// The last nanosecond of the last day of the local month.
+    pub fn end_of_month(&self) -> DateTime<F> {
+        let date = self.time_now.naive_local().date();
+        let last_day = Self::days_in_month(date.year(), date.month());
+        self.end_of_date(date.with_day(last_day).expect("Invalid last day of month"))
+    }
+
+    // ------------------------------------------------------------------
+    //           Year Boundaries
+    // ------------------------------------------------------------------
+    /// Midnight on January 1st of the local year.
+    pub fn start_of_year(&self) -> DateTime<F> {
+        let year = self.time_now.naive_local().year();
+        let date = NaiveDate::from_ymd_opt(year, 1, 1).expect("Invalid start-of-year date");
+        self.start_of_date(date)
+    }
+
+    /// The last nanosecond of December 31st of the local year.
+    pub fn end_of_year(&self) -> DateTime<F> {
+        let year = self.time_now.naive_local().year();
+        let date = NaiveDate::from_ymd_opt(year, 12, 31).expect("Invalid end-of-year date");
+        self.end_of_date(date)
+    }
+
+    // ------------------------------------------------------------------
+    //           Week Boundaries
+    // ------------------------------------------------------------------
+    /// Midnight on the most recent `first_day` on or before the current date.
+    pub fn start_of_week(&self, first_day: Weekday) -> DateTime<F> {
+        let date = self.time_now.naive_local().date();
+        let back = date.weekday().days_since(first_day) as u64;
+        self.start_of_date(date - chrono::Days::new(back))
+    }
+
+    /// The last nanosecond of the week that starts on `first_day`. If that
+    /// local time occurs twice, the later instant is used.
+    pub fn end_of_week(&self, first_day: Weekday) -> DateTime<F> {
+        let date = self.time_now.naive_local().date();
+        let forward = 6 - date.weekday().days_since(first_day) as u64;
+        self.end_of_date(date + chrono::Days::new(forward))
+    }
+
+    /// `(start_of_week, end_of_week)` computed from the same week.
+    pub fn week_bounds(&self, first_day: Weekday) -> (DateTime<F>, DateTime<F>) {
+        (self.start_of_week(first_day), self.end_of_week(first_day))
+    }
+
+    /// The next `target` weekday strictly after today, at the same time of day.
+    /// If today is already `target`, this is one week later.
+    pub fn next_weekday(&self, target: Weekday) -> DateTime<F> {
+        let naive = self.time_now.naive_local();
+        let ahead = 7 - naive.weekday().days_since(target) as u64;
+        let date = naive.date() + chrono::Days::new(ahead);
+        self.build_datetime_from_naive(date.and_time(naive.time()))
+    }
+
+    /// The most recent `target` weekday strictly before today, at the same time
+    /// of day. If today is already `target`, this is one week earlier.
+    pub fn previous_weekday(&self, target: Weekday) -> DateTime<F> {
+        let naive = self.time_now.naive_local();
+        let back = 7 - target.days_since(naive.weekday()) as u64;
+        let date = naive.date() - chrono::Days::new(back);
+        self.build_datetime_from_naive(date.and_time(naive.time()))
+    }
+
+    fn last_nanosecond_of(date: NaiveDate) -> NaiveDateTime {
+        date.and_hms_nano_opt(23, 59, 59, 999_999_999)
+            .expect("Invalid end-of-day time")
+    }
+
+    // ------------------------------------------------------------------
+    //           Component Replacement
+    // ------------------------------------------------------------------
+    /// Replaces the time of day with `time`, keeping the local date.
+    /// Fails if the resulting local time falls in a DST gap.
+    pub fn with_naive_time(&self, time: NaiveTime) -> Result<DateTime<F>, EasyTimeError> {
+        let date = self.time_now.naive_local().date();
+        self.try_build_datetime_from_naive(date.and_time(time))
+    }
+
+    /// Replaces the sub-second part with `ms` milliseconds.
+    /// Fails if `ms` is not below 1000.
+    pub fn with_millisecond(&self, ms: u32) -> Result<DateTime<F>, EasyTimeError> {
+        if ms >= 1000 {
+            return Err(EasyTimeError::InvalidComponents(format!(
+                "millisecond {}",
+                ms
+            )));
+        }
+        let subsecond = Duration::nanoseconds(self.time_now.nanosecond() as i64);
+        Ok(self.time_now.clone() - subsecond + Duration::milliseconds(ms as i64))
+    }
+
+    // ------------------------------------------------------------------
+    //           Annual Occurrences
+    // ------------------------------------------------------------------
+    /// The next `month`/`day` on or after the current date, at the same time
+    /// of day. Feb 29 falls back to Feb 28 in non-leap years.
+    pub fn next_occurrence_of(&self, month: u32, day: u32) -> DateTime<F> {
+        let naive = self.time_now.naive_local();
+        let mut date = Self::occurrence_in_year(naive.year(), month, day);
+        if date < naive.date() {
+            date = Self::occurrence_in_year(naive.year() + 1, month, day);
+        }
+        self.build_datetime_from_naive(date.and_time(naive.time()))
+    }
+
+    /// The most recent `month`/`day` on or before the current date, at the same
+    /// time of day. Feb 29 falls back to Feb 28 in non-leap years.
+    pub fn previous_occurrence_of(&self, month: u32, day: u32) -> DateTime<F> {
+        let naive = self.time_now.naive_local();
+        let mut date = Self::occurrence_in_year(naive.year(), month, day);
+        if date > naive.date() {
+            date = Self::occurrence_in_year(naive.year() - 1, month, day);
+        }
+        self.build_datetime_from_naive(date.and_time(naive.time()))
+    }
+
+    /// Days from `reference` until the next anniversary of `time_now`'s
+    /// month/day; 0 when `reference` falls on the anniversary.
+    pub fn days_until_next_anniversary(&self, reference: DateTime<F>) -> i64 {
+        let date = self.time_now.naive_local().date();
+        let from = reference.naive_local().date();
+        let next = EasyTime::from_time(reference).next_occurrence_of(date.month(), date.day());
+        (next.naive_local().date() - from).num_days()
+    }
+
+    fn occurrence_in_year(year: i32, month: u32, day: u32) -> NaiveDate {
+        let day = std::cmp::min(day, Self::days_in_month(year, month));
+        NaiveDate::from_ymd_opt(year, month, day).expect("Invalid month or day")
+    }
+
+    // ------------------------------------------------------------------
+    //           Unit Boundaries
+    // ------------------------------------------------------------------
+    /// `time_now` with the sub-second part zeroed.
+    pub fn truncate_subsecond(&self) -> DateTime<F> {
+        let (start, _) = self
+            .sub_day_bounds(TimeUnits::Seconds)
+            .expect("seconds are shorter than a day");
+        start
+    }
+
+    /// Zeroes the sub-second part of `time_now` in place.
+    pub fn truncate_subsecond_mut(&mut self) {
+        self.time_now = self.truncate_subsecond();
+    }
+
+    /// `time_now` with minutes, seconds and nanoseconds zeroed.
+    pub fn start_of_hour(&self) -> DateTime<F> {
+        self.truncate(TimeUnits::Hours)
+    }
+
+    /// The last nanosecond of the current hour (`hh:59:59.999999999`).
+    pub fn end_of_hour(&self) -> DateTime<F> {
+        let (_, next) = self
+            .sub_day_bounds(TimeUnits::Hours)
+            .expect("hours are shorter than a day");
+        next - Duration::nanoseconds(1)
+    }
+
+    /// `time_now` with seconds and nanoseconds zeroed.
+    pub fn start_of_minute(&self) -> DateTime<F> {
+        let (start, _) = self
+            .sub_day_bounds(TimeUnits::Minutes)
+            .expect("minutes are shorter than a day");
+        start
+    }
+
+    /// The last nanosecond of the current minute (`hh:mm:59.999999999`).
+    pub fn end_of_minute(&self) -> DateTime<F> {
+        let (_, next) = self
+            .sub_day_bounds(TimeUnits::Minutes)
+            .expect("minutes are shorter than a day");
+        next - Duration::nanoseconds(1)
+    }
+
+    /// `time_now` with every component finer than `unit` zeroed, e.g. `Hours`
+    /// clears minutes and below and `Months` moves to midnight on the 1st.
+    /// Weeks truncate to Monday. Units below a day are measured on the instant,
+    /// so a time in a repeated hour keeps its offset.
+    pub fn truncate(&self, unit: TimeUnits) -> DateTime<F> {
+        if let Some((start, _)) = self.sub_day_bounds(unit) {
+            return start;
+        }
+        let (start, _) = Self::period_bounds_naive(self.time_now.naive_local(), unit);
+        self.build_datetime_from_naive(start)
+    }
+
+    /// `time_now` rounded to the nearest `unit` boundary; exactly half way
+    /// rounds up, so 10:15:30 rounds to 10:16 for `Minutes`.
+    pub fn round(&self, unit: TimeUnits) -> DateTime<F> {
+        if let Some((start, end)) = self.sub_day_bounds(unit) {
+            let now = self.time_now.clone();
+            return if now.clone() - start.clone() >= end.clone() - now {
+                end
+            } else {
+                start
+            };
+        }
+        let naive = self.time_now.naive_local();
+        let (start, end) = Self::period_bounds_naive(naive, unit);
+        let nearest = if naive - start >= end - naive {
+            end
+        } else {
+            start
+        };
+        self.build_datetime_from_naive(nearest)
+    }
+
+    /// The first whole minute strictly after `time_now` whose minute-of-hour is
+    /// a multiple of `n`, e.g. 10:07 with `n = 15` gives 10:15.
+    ///
+    /// Panics if `n` is zero.
+    pub fn next_aligned_minute(&self, n: u32) -> DateTime<F> {
+        assert!(n > 0, "next_aligned_minute requires a positive n");
+        let minute = self.time_now.minute();
+        let target = std::cmp::min((minute / n + 1) * n, 60);
+        let into_minute = Duration::seconds(self.time_now.second() as i64)
+            + Duration::nanoseconds(self.time_now.nanosecond() as i64);
+        self.time_now.clone() - into_minute + Duration::minutes((target - minute) as i64)
+    }
+
+    // ------------------------------------------------------------------
+    //           Iterators
+    // ------------------------------------------------------------------
+    /// Remaining time until `target`, stepping down by `tick` and ending at zero.
+    /// A non-positive `tick` yields only the initial remaining time.
+    pub fn countdown_iter(
+        &self,
+        target: DateTime<F>,
+        tick: Duration,
+    ) -> impl Iterator<Item = Duration> {
+        let start = std::cmp::max(target - self.time_now.clone(), Duration::zero());
+        std::iter::successors(Some(start), move |remaining| {
+            if remaining.is_zero() || tick <= Duration::zero() {
+                None
+            } else {
+                Some(std::cmp::max(*remaining - tick, Duration::zero()))
+            }
+        })
+    }
+
+    /// Times from `start` (inclusive) towards `end` (exclusive), each `step_value`
+    /// `step_unit`s after the previous one. Every item is offset from `start`
+    /// directly, so month steps from the 31st do not drift. A non-positive
+    /// step yields nothing.
+    pub fn range(
+        start: DateTime<F>,
+        end: DateTime<F>,
+        step_value: i64,
+        step_unit: TimeUnits,
+    ) -> impl Iterator<Item = DateTime<F>> {
+        let anchor = Self::from_time(start);
+        (0i64..).map_while(move |step| {
+            if step_value <= 0 {
+                return None;
+            }
+            let time = anchor.apply_time_unit_forward(step.saturating_mul(step_value), step_unit);
+            (time < end).then_some(time)
+        })
+    }
+
+    /// One time per local calendar day from `time_now` through `target`,
+    /// inclusive, keeping the time of day. Walks backwards if `target` is
+    /// on an earlier date; a time of day skipped by DST moves forward to the
+    /// first valid local time.
+    pub fn dates_until(&self, target: DateTime<F>) -> impl Iterator<Item = DateTime<F>> {
+        let naive = self.time_now.naive_local();
+        let days = target.naive_local().date().num_days_from_ce() - naive.date().num_days_from_ce();
+        let anchor = self.clone();
+        (0..=days.abs()).map(move |step| {
+            let date = naive.date() + Duration::days((step * days.signum()) as i64);
+            anchor.first_valid_from(date.and_time(naive.time()))
+        })
+    }
+
+    /// Midnight on the first of each month from `time_now`'s month through
+    /// `end`'s month, inclusive. Yields nothing if `end` is in an earlier month.
+    pub fn iter_months(&self, end: DateTime<F>) -> impl Iterator<Item = DateTime<F>> {
+        let first = self.months_since_year_zero();
+        let last = Self::from_time(end).months_since_year_zero();
+        let anchor = self.clone();
+        (first..=last).map_while(move |index| {
+            Self::date_from_month_index(index).map(|date| anchor.start_of_date(date))
+        })
+    }
+
+    // ------------------------------------------------------------------
+    //           Time of Day
+    // ------------------------------------------------------------------
+    /// True before noon local time.
+    pub fn is_am(&self) -> bool {
+        self.time_now.hour() < 12
+    }
+
+    /// True from noon local time onwards.
+    pub fn is_pm(&self) -> bool {
+        !self.is_am()
+    }
+
+    // ------------------------------------------------------------------
+    //           Period Keys
+    // ------------------------------------------------------------------
+    /// A canonical key for the `unit`-long period containing `time_now`,
+    /// for grouping in analytics:
+    ///
+    /// - `Years`: `2023`
+    /// - `Quarters`: `2023-Q3`
+    /// - `Months`: `2023-07`
+    /// - `Weeks`: `2023-W28` (ISO week-year and week)
+    /// - `Days`: `2023-07-15`
+    /// - `Hours`: `2023-07-15T14`
+    /// - `Minutes`: `2023-07-15T14:05`
+    /// - `Seconds`: `2023-07-15T14:05:09`
+    ///
+    /// Panics for sub-second units, fortnights, and decades or longer.
+    pub fn period_key(&self, unit: TimeUnits) -> String {
+        let naive = self.time_now.naive_local();
+        match unit {
+            TimeUnits::Years => naive.format("%Y").to_string(),
+            TimeUnits::Quarters => format!("{}-Q{}", naive.year(), naive.month0() / 3 + 1),
+            TimeUnits::Months => naive.format("%Y-%m").to_string(),
+            TimeUnits::Weeks => naive.format("%G-W%V").to_string(),
+            TimeUnits::Days => naive.format("%Y-%m-%d").to_string(),
+            TimeUnits::Hours => naive.format("%Y-%m-%dT%H").to_string(),
+            TimeUnits::Minutes => naive.format("%Y-%m-%dT%H:%M").to_string(),
+            TimeUnits::Seconds => naive.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            TimeUnits::Nanoseconds
+            | TimeUnits::Microseconds
+            | TimeUnits::Milliseconds
+            | TimeUnits::Fortnights
+            | TimeUnits::Decades
+            | TimeUnits::Centuries
+            | TimeUnits::Millenniums => panic!("period_key does not support {:?}", unit),
+        }
+    }
+
+    // ------------------------------------------------------------------
+    //               Ordering
+    // ------------------------------------------------------------------
+    /// Sorts `items` in place by instant, keeping the relative order of equal instants.
+    pub fn sort_chronological(items: &mut [EasyTime<F>]) {
+        items.sort_by(|a, b| a.time_now.cmp(&b.time_now));
+    }
+
+    /// Orders by local calendar date only; two times on the same day are `Equal`.
+    pub fn cmp_by_date(&self, other: &EasyTime<F>) -> std::cmp::Ordering {
+        let date = self.time_now.naive_local().date();
+        date.cmp(&other.time_now.naive_local().date())
+    }
+
+    /// Returns `true` if every instant is strictly later than the one before it.
+    /// Duplicate instants break strict ascending order.
+    pub fn is_strictly_ascending(items: &[EasyTime<F>]) -> bool {
+        items
+            .windows(2)
+            .all(|pair| pair[0].time_now < pair[1].time_now)
+    }
+
+    /// The earliest instant in `items`, or `None` if it is empty.
+    pub fn earliest_of(items: &[EasyTime<F>]) -> Option<DateTime<F>> {
+        items.iter().map(|item| item.time_now.clone()).min()
+    }
+
+    /// The latest instant in `items`, or `None` if it is empty.
+    pub fn latest_of(items: &[EasyTime<F>]) -> Option<DateTime<F>> {
+        items.iter().map(|item| item.time_now.clone()).max()
+    }
+
+    // ------------------------------------------------------------------
+    //               Period Progress
+    // ------------------------------------------------------------------
+    /// The first day of the month `index` months after year zero.
+    fn date_from_month_index(index: i64) -> Option<NaiveDate> {
+        let year = i32::try_from(index.div_euclid(12)).ok()?;
+        NaiveDate::from_ymd_opt(year, index.rem_euclid(12) as u32 + 1, 1)
+    }
+
+    /// The start of the sub-day `unit` period containing `time_now` and the
+    /// start of the next one, measured back from the instant itself so both
+    /// keep `time_now`'s offset inside a repeated hour. `None` for days and longer.
+    fn sub_day_bounds(&self, unit: TimeUnits) -> Option<(DateTime<F>, DateTime<F>)> {
+        let step = Self::fixed_unit_duration(unit).filter(|step| *step < Duration::days(1))?;
+        let naive = self.time_now.naive_local();
+        let (start, _) = Self::period_bounds_naive(naive, unit);
+        let start = Self::offset_saturating(&self.time_now, start - naive);
+        let end = Self::offset_saturating(&start, step);
+        Some((start, end))
+    }
+
+    /// The local start of the `unit`-long period containing `naive`, and the
+    /// start of the following one. Weeks start on Monday and fortnights are
+    /// counted from Monday, January 1st of year 1; decades, centuries and
+    /// millenniums start on years divisible by 10, 100 and 1000.
+    fn period_bounds_naive(
+        naive: NaiveDateTime,
+        unit: TimeUnits,
+    ) -> (NaiveDateTime, NaiveDateTime) {
+        let months = match unit {
+            TimeUnits::Months => 1,
+            TimeUnits::Quarters => 3,
+            TimeUnits::Years => 12,
+            TimeUnits::Decades => 120,
+            TimeUnits::Centuries => 1_200,
+            TimeUnits::Millenniums => 12_000,
+            TimeUnits::Weeks => {
+                let days_into_week = naive.weekday().num_days_from_monday() as i64;
+                let start =
+                    (naive.date() - Duration::days(days_into_week)).and_time(NaiveTime::MIN);
+                let end = start
+                    .checked_add_signed(Duration::weeks(1))
+                    .unwrap_or(NaiveDateTime::MAX);
+                return (start, end);
+            }
+            TimeUnits::Fortnights => {
+                let days_into_fortnight = (naive.num_days_from_ce() as i64 - 1).rem_euclid(14);
+                let start =
+                    (naive.date() - Duration::days(days_into_fortnight)).and_time(NaiveTime::MIN);
+                let end = start
+                    .checked_add_signed(Duration::weeks(2))
+                    .unwrap_or(NaiveDateTime::MAX);
+                return (start, end);
+            }
+            _ => {
+                let step = Self::fixed_unit_duration(unit).expect("fixed-length unit");
+                let midnight = naive.date().and_time(NaiveTime::MIN);
+                let into_day = Self::total_nanos(naive - midnight);
+                let into_period = into_day % Self::total_nanos(step);
+                let start = naive - Duration::nanoseconds(into_period as i64);
+                let end = start.checked_add_signed(step).unwrap_or(NaiveDateTime::MAX);
+                return (start, end);
+            }
+        };
+        let index = naive.year() as i64 * 12 + naive.month0() as i64;
+        let start_index = index.div_euclid(months) * months;
+        let start = Self::date_from_month_index(start_index)
+            .map_or(NaiveDateTime::MIN, |date| date.and_time(NaiveTime::MIN));
+        let end = Self::date_from_month_index(start_index + months)
+            .map_or(NaiveDateTime::MAX, |date| date.and_time(NaiveTime::MIN));
+        (start, end)
+    }
+
+    /// How far `time_now` is through the current period of `unit`, from 0.0
+    /// at its start towards 1.0 at its end, measured on the local wall clock.
+    pub fn fraction_through(&self, unit: TimeUnits) -> f64 {
+        let naive = self.time_now.naive_local();
+        let (start, end) = Self::period_bounds_naive(naive, unit);
+        let elapsed = Self::total_nanos(naive - start) as f64;
+        let length = Self::total_nanos(end - start) as f64;
+        elapsed / length
+    }
+
+    /// Expands `start..end` outward to whole `unit` boundaries: `start` is
+    /// floored and `end` is ceiled unless it already sits on a boundary.
+    pub fn align_range(start: DateTime<F>, end: DateTime<F>, unit: TimeUnits) -> Interval<F> {
+        let (lower, upper) = (Self::from_time(start), Self::from_time(end));
+        let (floor, _) = Self::period_bounds_naive(lower.time_now.naive_local(), unit);
+        let end_naive = upper.time_now.naive_local();
+        let ceil = match Self::period_bounds_naive(end_naive, unit) {
+            (period_start, _) if period_start == end_naive => end_naive,
+            (_, next_start) => next_start,
+        };
+        (
+            lower.build_datetime_from_naive(floor),
+            upper.build_datetime_from_naive(ceil),
+        )
+    }
+
+    /// The nearest `unit` boundary strictly before `time_now`: 10:30 gives
+    /// 10:00 for `Hours`, and exactly 10:00 gives 09:00.
+    pub fn last_completed_boundary(&self, unit: TimeUnits) -> DateTime<F> {
+        let naive = self.time_now.naive_local();
+        let (mut start, _) = Self::period_bounds_naive(naive, unit);
+        if start == naive {
+            start = Self::period_bounds_naive(naive - Duration::nanoseconds(1), unit).0;
+        }
+        self.build_datetime_from_naive(start)
+    }
+
+    /// `fraction_through` as a percentage from 0.0 to 100.0, e.g. for gauges.
+    pub fn elapsed_percent_of(&self, unit: TimeUnits) -> f64 {
+        self.fraction_through(unit) * 100.0
+    }
+
+    // ------------------------------------------------------------------
+    //               Timezone Conversion
+    // ------------------------------------------------------------------
+    /// The same instant viewed in `tz`, keeping `value`.
+    pub fn with_timezone<T: TimeZone>(&self, tz: &T) -> EasyTime<T>
+    where
+        T::Offset: std::fmt::Display,
+    {
+        EasyTime::new_with_time(self.value, self.time_now.with_timezone(tz))
+    }
+
+    /// The same instant viewed in UTC, keeping `value`.
+    pub fn to_utc(&self) -> EasyTime<Utc> {
+        self.with_timezone(&Utc)
+    }
+
+    /// The same instant viewed in the system's local timezone, keeping `value`.
+    pub fn to_local(&self) -> EasyTime<Local> {
+        self.with_timezone(&Local)
+    }
 }
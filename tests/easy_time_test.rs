@@ -1,13 +1,15 @@
 extern crate easy_time;
-use chrono::prelude::Local;
+use chrono::prelude::{DateTime, Local};
 use chrono::Duration;
-use easy_time::EasyTime;
+use easy_time::{
+    Direction, EasyTime, EasyTimeError, RelativeLabels, TimeDiff, TimeUnits, Tm,
+};
 
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{TimeZone, Utc};
+    use chrono::{Datelike, TimeZone, Timelike, Utc};
 
     // Test the EasyTime::new method
     #[test]
@@ -372,4 +374,2539 @@ mod tests {
         );
     }
 
+    // Test calendar_days_between against the elapsed-time days_between
+    #[test]
+    fn test_calendar_days_between_ignores_time() {
+        let late = Local.with_ymd_and_hms(2023, 6, 14, 23, 0, 0).unwrap();
+        let early = Local.with_ymd_and_hms(2023, 6, 15, 1, 0, 0).unwrap();
+        let from = EasyTime::new_with_time(0, late);
+        let to = EasyTime::new_with_time(0, early);
+        // Only two hours elapsed, but the calendar date changed
+        assert_eq!(from.days_between(&to), 0);
+        assert_eq!(from.calendar_days_between(&to), 1);
+        assert_eq!(to.calendar_days_between(&from), -1);
+    }
+
+    // Test calendar_days_between and days_between agree on whole days
+    #[test]
+    fn test_calendar_days_between_whole_days() {
+        let start = Utc.with_ymd_and_hms(2023, 2, 25, 12, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2023, 3, 2, 12, 0, 0).unwrap();
+        let from = EasyTime::new_with_time(0, start);
+        let to = EasyTime::new_with_time(0, end);
+        assert_eq!(from.days_between(&to), 5);
+        assert_eq!(from.calendar_days_between(&to), 5);
+    }
+
+    // Test to_string_with_format_offset_colon with a half-hour offset
+    #[test]
+    fn test_to_string_with_format_offset_colon_fixed() {
+        let tz = chrono::FixedOffset::east_opt(5400).unwrap();
+        let date_time = tz.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let result = easy_time.to_string_with_format_offset_colon("%Y-%m-%d %H:%M");
+        assert_eq!(result, "2023-10-01 12:00 +01:30");
+    }
+
+    // Test to_string_with_format_offset_colon for Utc, whose offset displays as "UTC"
+    #[test]
+    fn test_to_string_with_format_offset_colon_utc() {
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let result = easy_time.to_string_with_format_offset_colon("%Y-%m-%d %H:%M");
+        assert_eq!(result, "2023-10-01 12:00 +00:00");
+    }
+
+    // Test set_value_checked accepting a value that is safe for the unit
+    #[test]
+    fn test_set_value_checked_accepts_safe_value() {
+        let date_time = Local.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let mut easy_time = EasyTime::new_with_time(0, date_time);
+        assert_eq!(
+            easy_time.set_value_checked(5, TimeUnits::Millenniums),
+            Ok(())
+        );
+        assert_eq!(easy_time.value, 5);
+        let expected = Local.with_ymd_and_hms(7023, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(easy_time.millenniums_from_now(), expected);
+    }
+
+    // Test set_value_checked rejecting a value that overflows Millenniums
+    #[test]
+    fn test_set_value_checked_rejects_overflow() {
+        let date_time = Local.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let mut easy_time = EasyTime::new_with_time(3, date_time);
+        let value = 3_000_000;
+        assert_eq!(
+            easy_time.set_value_checked(value, TimeUnits::Millenniums),
+            Err(EasyTimeError::ValueOverflow {
+                value,
+                unit: TimeUnits::Millenniums
+            })
+        );
+        // The previous value is kept when the new one is rejected
+        assert_eq!(easy_time.value, 3);
+    }
+
+    // Test set_value_checked rejecting months that leave chrono's date range
+    #[test]
+    fn test_set_value_checked_rejects_months_out_of_range() {
+        let date_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let mut easy_time = EasyTime::new_with_time(0, date_time);
+        let value = i32::MAX as i64;
+        assert_eq!(
+            easy_time.set_value_checked(value, TimeUnits::Months),
+            Err(EasyTimeError::ValueOverflow {
+                value,
+                unit: TimeUnits::Months
+            })
+        );
+        assert_eq!(easy_time.set_value_checked(1200, TimeUnits::Months), Ok(()));
+        assert_eq!(
+            easy_time.months_from_now(),
+            Utc.with_ymd_and_hms(2123, 1, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    // Test set_value_checked rejecting seconds and days past the latest instant
+    #[test]
+    fn test_set_value_checked_rejects_seconds_out_of_range() {
+        let date_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let mut easy_time = EasyTime::new_with_time(0, date_time);
+        let value = i64::MAX / 1000;
+        assert_eq!(
+            easy_time.set_value_checked(value, TimeUnits::Seconds),
+            Err(EasyTimeError::ValueOverflow {
+                value,
+                unit: TimeUnits::Seconds
+            })
+        );
+        let days = i64::MAX / 86_400_000;
+        assert!(easy_time.set_value_checked(days, TimeUnits::Days).is_err());
+        assert_eq!(easy_time.value, 0);
+    }
+
+    // Test intersection of two overlapping intervals
+    #[test]
+    fn test_intersection_overlapping() {
+        let t = |h| Utc.with_ymd_and_hms(2023, 5, 1, h, 0, 0).unwrap();
+        let overlap = EasyTime::intersection((t(9), t(12)), (t(11), t(14)));
+        assert_eq!(overlap, Some((t(11), t(12))));
+    }
+
+    // Test intersection when one interval is nested inside the other
+    #[test]
+    fn test_intersection_nested() {
+        let t = |h| Utc.with_ymd_and_hms(2023, 5, 1, h, 0, 0).unwrap();
+        let overlap = EasyTime::intersection((t(8), t(18)), (t(10), t(11)));
+        assert_eq!(overlap, Some((t(10), t(11))));
+    }
+
+    // Test intersection of touching and disjoint intervals
+    #[test]
+    fn test_intersection_touching_and_disjoint() {
+        let t = |h| Utc.with_ymd_and_hms(2023, 5, 1, h, 0, 0).unwrap();
+        // Half-open intervals that only share an endpoint do not overlap
+        assert_eq!(EasyTime::intersection((t(9), t(10)), (t(10), t(11))), None);
+        assert_eq!(EasyTime::intersection((t(9), t(10)), (t(12), t(13))), None);
+    }
+
+    // Test merge of overlapping and touching intervals
+    #[test]
+    fn test_merge_overlapping() {
+        let t = |h| Utc.with_ymd_and_hms(2023, 5, 1, h, 0, 0).unwrap();
+        assert_eq!(
+            EasyTime::merge((t(11), t(14)), (t(9), t(12))),
+            Ok((t(9), t(14)))
+        );
+        assert_eq!(
+            EasyTime::merge((t(9), t(10)), (t(10), t(11))),
+            Ok((t(9), t(11)))
+        );
+        assert_eq!(
+            EasyTime::merge((t(8), t(18)), (t(10), t(11))),
+            Ok((t(8), t(18)))
+        );
+    }
+
+    // Test merge of disjoint intervals returning the gap
+    #[test]
+    fn test_merge_disjoint_returns_gap() {
+        let t = |h| Utc.with_ymd_and_hms(2023, 5, 1, h, 0, 0).unwrap();
+        assert_eq!(
+            EasyTime::merge((t(13), t(15)), (t(9), t(10))),
+            Err((t(10), t(13)))
+        );
+    }
+
+    // Test to_relative_tense for single-unit values
+    #[test]
+    fn test_to_relative_tense_near() {
+        let reference = Utc.with_ymd_and_hms(2023, 6, 15, 12, 0, 0).unwrap();
+        let yesterday = EasyTime::new_with_time(0, reference - Duration::days(1));
+        let tomorrow = EasyTime::new_with_time(0, reference + Duration::days(1));
+        let last_week = EasyTime::new_with_time(0, reference - Duration::days(8));
+        let next_month = EasyTime::new_with_time(0, reference + Duration::days(31));
+        assert_eq!(yesterday.to_relative_tense(reference), "yesterday");
+        assert_eq!(tomorrow.to_relative_tense(reference), "tomorrow");
+        assert_eq!(last_week.to_relative_tense(reference), "last week");
+        assert_eq!(next_month.to_relative_tense(reference), "next month");
+    }
+
+    // Test to_relative_tense using calendar dates for yesterday and tomorrow
+    #[test]
+    fn test_to_relative_tense_calendar_days() {
+        let reference = Utc.with_ymd_and_hms(2023, 6, 15, 23, 59, 0).unwrap();
+        let next_day =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 6, 16, 0, 1, 0).unwrap());
+        assert_eq!(next_day.to_relative_tense(reference), "tomorrow");
+        let noon = Utc.with_ymd_and_hms(2023, 6, 15, 12, 0, 0).unwrap();
+        let two_dates_back = EasyTime::new_with_time(0, noon - Duration::hours(47));
+        assert_ne!(two_dates_back.to_relative_tense(noon), "yesterday");
+        assert_eq!(two_dates_back.to_relative_tense(noon), "1 day ago");
+    }
+
+    // Test to_relative_tense falling back to counted units
+    #[test]
+    fn test_to_relative_tense_far() {
+        let reference = Utc.with_ymd_and_hms(2023, 6, 15, 12, 0, 0).unwrap();
+        let three_days_ago = EasyTime::new_with_time(0, reference - Duration::days(3));
+        let in_two_hours = EasyTime::new_with_time(0, reference + Duration::hours(2));
+        let same = EasyTime::new_with_time(0, reference);
+        assert_eq!(three_days_ago.to_relative_tense(reference), "3 days ago");
+        assert_eq!(in_two_hours.to_relative_tense(reference), "in 2 hours");
+        assert_eq!(same.to_relative_tense(reference), "just now");
+    }
+
+    // Test offset_saturating clamping near the maximum instant
+    #[test]
+    fn test_offset_saturating_clamps_to_max() {
+        let near_max = DateTime::<Utc>::MAX_UTC - Duration::days(1);
+        let result = EasyTime::offset_saturating(&near_max, Duration::days(10));
+        assert_eq!(result, DateTime::<Utc>::MAX_UTC);
+    }
+
+    // Test offset_saturating clamping near the minimum instant and passing through otherwise
+    #[test]
+    fn test_offset_saturating_clamps_to_min() {
+        let tz = chrono::FixedOffset::east_opt(3600).unwrap();
+        let near_min = DateTime::<Utc>::MIN_UTC.with_timezone(&tz) + Duration::days(1);
+        let result = EasyTime::offset_saturating(&near_min, Duration::days(-10));
+        assert_eq!(result, DateTime::<Utc>::MIN_UTC);
+
+        let date_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let shifted = EasyTime::offset_saturating(&date_time, Duration::hours(5));
+        assert_eq!(shifted, date_time + Duration::hours(5));
+    }
+
+    // Test parse_flexible with ISO 8601 and RFC 2822 inputs
+    #[test]
+    fn test_parse_flexible_iso_and_rfc2822() {
+        let expected = Utc.with_ymd_and_hms(2023, 10, 1, 12, 30, 0).unwrap();
+        let iso = EasyTime::parse_flexible("2023-10-01T14:30:00+02:00", 0).unwrap();
+        let naive_iso = EasyTime::parse_flexible("2023-10-01 12:30:00", 0).unwrap();
+        let rfc2822 = EasyTime::parse_flexible("Sun, 1 Oct 2023 12:30:00 +0000", 0).unwrap();
+        assert_eq!(iso.time_now, expected);
+        assert_eq!(naive_iso.time_now, expected);
+        assert_eq!(rfc2822.time_now, expected);
+        assert_eq!(iso.value, 0);
+    }
+
+    // Test parse_flexible with US and European slash dates
+    #[test]
+    fn test_parse_flexible_slash_dates() {
+        // Ambiguous input prefers the US month/day reading
+        let us = EasyTime::parse_flexible("01/02/2023", 0).unwrap();
+        assert_eq!(
+            us.time_now,
+            Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap()
+        );
+        // Only valid as day/month, so the European reading is used
+        let eu = EasyTime::parse_flexible("25/12/2023 08:15:00", 0).unwrap();
+        assert_eq!(
+            eu.time_now,
+            Utc.with_ymd_and_hms(2023, 12, 25, 8, 15, 0).unwrap()
+        );
+    }
+
+    // Test parse_flexible rejecting unparseable text
+    #[test]
+    fn test_parse_flexible_error() {
+        let result = EasyTime::parse_flexible("next tuesday-ish", 0);
+        assert!(matches!(result, Err(EasyTimeError::ParseError(_))));
+    }
+
+    // Test to_epoch_and_zone / from_epoch_and_zone round trip
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_epoch_and_zone_round_trip() {
+        let london = chrono_tz::Europe::London;
+        let date_time = london.with_ymd_and_hms(2023, 7, 1, 9, 30, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let (ts, zone) = easy_time.to_epoch_and_zone();
+        assert_eq!(zone, "Europe/London");
+        let restored = EasyTime::from_epoch_and_zone(ts, &zone).unwrap();
+        assert_eq!(restored.time_now, date_time);
+        assert_eq!(restored.to_string(), "2023-07-01 09:30:00");
+        assert_eq!(
+            EasyTime::from_epoch_and_zone(ts, "Mars/Olympus_Mons"),
+            Err(EasyTimeError::InvalidTimeZone(
+                "Mars/Olympus_Mons".to_string()
+            ))
+        );
+    }
+
+    // Test is_within_business_hours on weekdays and weekends
+    #[test]
+    fn test_is_within_business_hours() {
+        // 2023-10-04 is a Wednesday, 2023-10-07 is a Saturday
+        let at = |day, hour| {
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 10, day, hour, 0, 0).unwrap())
+        };
+        let weekday_morning = at(4, 10);
+        let weekday_evening = at(4, 18);
+        let closing_time = at(4, 17);
+        let saturday = at(7, 10);
+        assert!(weekday_morning.is_within_business_hours(9, 17));
+        assert!(!weekday_evening.is_within_business_hours(9, 17));
+        assert!(!closing_time.is_within_business_hours(9, 17));
+        assert!(!saturday.is_within_business_hours(9, 17));
+    }
+
+    // Test next_business_day from a Saturday
+    #[test]
+    fn test_next_business_day_from_weekend() {
+        let saturday = Utc.with_ymd_and_hms(2023, 10, 7, 9, 45, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, saturday);
+        let monday = Utc.with_ymd_and_hms(2023, 10, 9, 9, 45, 0).unwrap();
+        assert_eq!(easy_time.next_business_day(), monday);
+        assert_eq!(easy_time.next_business_day_strict(), monday);
+    }
+
+    // Test next_business_day vs next_business_day_strict from a Wednesday
+    #[test]
+    fn test_next_business_day_from_weekday() {
+        let wednesday = Utc.with_ymd_and_hms(2023, 10, 4, 9, 45, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, wednesday);
+        assert_eq!(easy_time.next_business_day(), wednesday);
+        let thursday = Utc.with_ymd_and_hms(2023, 10, 5, 9, 45, 0).unwrap();
+        assert_eq!(easy_time.next_business_day_strict(), thursday);
+    }
+
+    // Test week_bounds returning a consistent start/end pair
+    #[test]
+    fn test_week_bounds() {
+        // 2023-10-04 is a Wednesday
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 4, 15, 20, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let (start, end) = easy_time.week_bounds(chrono::Weekday::Mon);
+        assert_eq!(start, Utc.with_ymd_and_hms(2023, 10, 2, 0, 0, 0).unwrap());
+        assert_eq!(start, easy_time.start_of_week(chrono::Weekday::Mon));
+        assert_eq!(end, easy_time.end_of_week(chrono::Weekday::Mon));
+        assert_eq!(end - start, Duration::days(7) - Duration::nanoseconds(1));
+        assert!(start <= date_time && date_time <= end);
+        assert_eq!(start.iso_week(), end.iso_week());
+    }
+
+    // Test week_bounds with a Sunday-first week
+    #[test]
+    fn test_week_bounds_sunday_start() {
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 8, 6, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let (start, end) = easy_time.week_bounds(chrono::Weekday::Sun);
+        assert_eq!(start, Utc.with_ymd_and_hms(2023, 10, 8, 0, 0, 0).unwrap());
+        assert_eq!(
+            end.date_naive(),
+            chrono::NaiveDate::from_ymd_opt(2023, 10, 14).unwrap()
+        );
+    }
+
+    // Test from_timestamp_str with a numeric string
+    #[test]
+    fn test_from_timestamp_str_valid() {
+        let easy_time = EasyTime::from_timestamp_str("1696161600").unwrap();
+        let expected = Utc.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap();
+        assert_eq!(easy_time.time_now, expected);
+        assert_eq!(easy_time.value, 0);
+    }
+
+    // Test from_timestamp_str distinguishing parse and range errors
+    #[test]
+    fn test_from_timestamp_str_errors() {
+        assert!(matches!(
+            EasyTime::from_timestamp_str("yesterday"),
+            Err(EasyTimeError::ParseError(_))
+        ));
+        assert_eq!(
+            EasyTime::from_timestamp_str("9223372036854775807"),
+            Err(EasyTimeError::TimestampOutOfRange(i64::MAX))
+        );
+    }
+
+    // Test with_naive_time replacing the time of day
+    #[test]
+    fn test_with_naive_time() {
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 1, 22, 5, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let time = chrono::NaiveTime::from_hms_opt(9, 15, 30).unwrap();
+        let expected = Utc.with_ymd_and_hms(2023, 10, 1, 9, 15, 30).unwrap();
+        assert_eq!(easy_time.with_naive_time(time), Ok(expected));
+    }
+
+    // Test with_naive_time landing in a DST gap (America/New_York springs forward at 02:00)
+    #[test]
+    fn test_with_naive_time_dst_gap() {
+        let new_york = chrono_tz::America::New_York;
+        let date_time = new_york.with_ymd_and_hms(2023, 3, 12, 0, 30, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let time = chrono::NaiveTime::from_hms_opt(2, 30, 0).unwrap();
+        let naive = date_time.date_naive().and_time(time);
+        assert_eq!(
+            easy_time.with_naive_time(time),
+            Err(EasyTimeError::NonExistentLocalTime(naive))
+        );
+    }
+
+    // Test previous_occurrence_of for a date earlier this year
+    #[test]
+    fn test_previous_occurrence_of_this_year() {
+        let date_time = Utc.with_ymd_and_hms(2023, 6, 15, 8, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let expected = Utc.with_ymd_and_hms(2023, 3, 1, 8, 0, 0).unwrap();
+        assert_eq!(easy_time.previous_occurrence_of(3, 1), expected);
+        // Today counts as the previous occurrence
+        assert_eq!(easy_time.previous_occurrence_of(6, 15), date_time);
+    }
+
+    // Test previous_occurrence_of rolling back to last year, with the Feb 29 fallback
+    #[test]
+    fn test_previous_occurrence_of_last_year() {
+        let date_time = Utc.with_ymd_and_hms(2023, 6, 15, 8, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let expected = Utc.with_ymd_and_hms(2022, 12, 25, 8, 0, 0).unwrap();
+        assert_eq!(easy_time.previous_occurrence_of(12, 25), expected);
+
+        let leap = EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2025, 1, 10, 0, 0, 0).unwrap());
+        let leap_day = Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap();
+        assert_eq!(leap.previous_occurrence_of(2, 29), leap_day);
+        let fallback = Utc.with_ymd_and_hms(2023, 2, 28, 8, 0, 0).unwrap();
+        assert_eq!(easy_time.previous_occurrence_of(2, 29), fallback);
+    }
+
+    // Test format_all formatting several instants with one format string
+    #[test]
+    fn test_format_all() {
+        let items = vec![
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap()),
+            EasyTime::new_with_time(1, Utc.with_ymd_and_hms(2023, 6, 15, 12, 30, 0).unwrap()),
+            EasyTime::new_with_time(2, Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap()),
+        ];
+        let formatted = EasyTime::format_all(&items, "%d.%m.%Y %H:%M");
+        assert_eq!(
+            formatted,
+            vec!["01.01.2023 00:00", "15.06.2023 12:30", "29.02.2024 23:59"]
+        );
+    }
+
+    // Test to_ordinal_date zero-padding the day of year
+    #[test]
+    fn test_to_ordinal_date() {
+        let new_year =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 1, 1, 9, 0, 0).unwrap());
+        assert_eq!(new_year.to_ordinal_date(), "2023-001");
+        let october =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 10, 1, 9, 0, 0).unwrap());
+        assert_eq!(october.to_ordinal_date(), "2023-274");
+    }
+
+    // Test from_ordinal_date round trip and rejection of invalid input
+    #[test]
+    fn test_from_ordinal_date_round_trip() {
+        let parsed = EasyTime::from_ordinal_date("2024-366").unwrap();
+        assert_eq!(
+            parsed.time_now,
+            Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap()
+        );
+        assert_eq!(parsed.to_ordinal_date(), "2024-366");
+        assert!(matches!(
+            EasyTime::from_ordinal_date("2023-366"),
+            Err(EasyTimeError::ParseError(_))
+        ));
+    }
+
+    // Test days_until_next_anniversary for upcoming, passed, and same-day birthdays
+    #[test]
+    fn test_days_until_next_anniversary() {
+        let birthday =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(1990, 7, 20, 6, 0, 0).unwrap());
+        let before = Utc.with_ymd_and_hms(2023, 7, 10, 18, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2023, 7, 21, 18, 0, 0).unwrap();
+        let on_the_day = Utc.with_ymd_and_hms(2023, 7, 20, 18, 0, 0).unwrap();
+        assert_eq!(birthday.days_until_next_anniversary(before), 10);
+        assert_eq!(birthday.days_until_next_anniversary(after), 365);
+        assert_eq!(birthday.days_until_next_anniversary(on_the_day), 0);
+    }
+
+    // Test start_of_minute and end_of_minute
+    #[test]
+    fn test_start_and_end_of_minute() {
+        let date_time =
+            Utc.with_ymd_and_hms(2023, 10, 1, 10, 30, 45).unwrap() + Duration::milliseconds(250);
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let start = Utc.with_ymd_and_hms(2023, 10, 1, 10, 30, 0).unwrap();
+        assert_eq!(easy_time.start_of_minute(), start);
+        let end = start + Duration::seconds(60) - Duration::nanoseconds(1);
+        assert_eq!(easy_time.end_of_minute(), end);
+    }
+
+    // Test start_of_hour and end_of_hour
+    #[test]
+    fn test_start_and_end_of_hour() {
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 1, 10, 37, 22).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let start = Utc.with_ymd_and_hms(2023, 10, 1, 10, 0, 0).unwrap();
+        assert_eq!(easy_time.start_of_hour(), start);
+        let end = Utc.with_ymd_and_hms(2023, 10, 1, 10, 59, 59).unwrap()
+            + Duration::nanoseconds(999_999_999);
+        assert_eq!(easy_time.end_of_hour(), end);
+    }
+
+    // Test tz_abbreviation for Utc, a named zone, and a FixedOffset
+    #[test]
+    fn test_tz_abbreviation() {
+        let utc = EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(utc.tz_abbreviation(), "UTC");
+
+        let new_york = chrono_tz::America::New_York;
+        let winter =
+            EasyTime::new_with_time(0, new_york.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(winter.tz_abbreviation(), "EST");
+
+        // FixedOffset has no name, so the numeric offset is returned instead
+        let tz = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        let fixed = EasyTime::new_with_time(0, tz.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(fixed.tz_abbreviation(), "+02:00");
+    }
+
+    // Test lerp at the ends and midpoint, and clamping outside 0..=1
+    #[test]
+    fn test_lerp() {
+        let a = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let b = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        assert_eq!(EasyTime::lerp(a, b, 0.0), a);
+        assert_eq!(EasyTime::lerp(a, b, 1.0), b);
+        assert_eq!(EasyTime::lerp(a, b, 0.5), a + Duration::hours(12));
+        assert_eq!(EasyTime::lerp(a, b, 1.5), b);
+        assert_eq!(EasyTime::lerp(a, b, -0.5), a);
+    }
+
+    // Test range_crosses_dst. The result depends on the machine's TZ: in a zone
+    // with DST (e.g. TZ=America/New_York) the spring-forward weekend range
+    // crosses a transition; in a zone without DST (e.g. UTC) nothing does.
+    #[test]
+    fn test_range_crosses_dst() {
+        let winter = Local.with_ymd_and_hms(2023, 1, 15, 12, 0, 0).unwrap();
+        let summer = Local.with_ymd_and_hms(2023, 7, 15, 12, 0, 0).unwrap();
+        let observes_dst = winter.offset() != summer.offset();
+
+        // Fully inside standard time (northern or southern hemisphere winter)
+        let standard_start = Local.with_ymd_and_hms(2023, 1, 10, 0, 0, 0).unwrap();
+        let standard_end = Local.with_ymd_and_hms(2023, 1, 20, 0, 0, 0).unwrap();
+        assert!(!EasyTime::<Local>::range_crosses_dst(
+            standard_start,
+            standard_end
+        ));
+
+        // From winter into summer always spans a spring-forward transition
+        assert_eq!(
+            EasyTime::<Local>::range_crosses_dst(winter, summer),
+            observes_dst
+        );
+    }
+
+    // Test iso_week_tuple where the ISO week-year differs from the calendar year
+    #[test]
+    fn test_iso_week_tuple() {
+        let new_year =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2021, 1, 1, 12, 0, 0).unwrap());
+        assert_eq!(new_year.iso_week_tuple(), (2020, 53, 5));
+        let sunday =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 10, 8, 12, 0, 0).unwrap());
+        assert_eq!(sunday.iso_week_tuple(), (2023, 40, 7));
+    }
+
+    // Test abs_value, negate_value and direction
+    #[test]
+    fn test_value_sign_helpers() {
+        let date_time = Utc.with_ymd_and_hms(2023, 3, 15, 12, 0, 0).unwrap();
+        let mut easy_time = EasyTime::new_with_time(-2, date_time);
+        assert_eq!(easy_time.direction(), Direction::Backward);
+
+        easy_time.abs_value();
+        assert_eq!(easy_time.value, 2);
+        assert_eq!(easy_time.direction(), Direction::Forward);
+
+        easy_time.negate_value();
+        assert_eq!(easy_time.value, -2);
+        assert_eq!(easy_time.direction(), Direction::Backward);
+
+        easy_time.set_value(0);
+        assert_eq!(easy_time.direction(), Direction::Zero);
+    }
+
+    // Test to_filename_timestamp shape and absence of illegal characters
+    #[test]
+    fn test_to_filename_timestamp() {
+        let date_time =
+            Utc.with_ymd_and_hms(2023, 10, 1, 14, 30, 5).unwrap() + Duration::milliseconds(42);
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let name = easy_time.to_filename_timestamp();
+        assert_eq!(name, "20231001_143005_042");
+
+        let now = EasyTime::new(0).to_filename_timestamp();
+        let parts: Vec<&str> = now.split('_').collect();
+        assert_eq!(
+            parts.iter().map(|p| p.len()).collect::<Vec<_>>(),
+            vec![8, 6, 3]
+        );
+        assert!(parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit())));
+        assert!(!now.contains(':'));
+    }
+
+    // Test EasyTime::<Utc>::epoch
+    #[test]
+    fn test_epoch() {
+        let epoch = EasyTime::<Utc>::epoch();
+        assert_eq!(epoch.to_timestamp(), 0);
+        assert_eq!(epoch.value, 0);
+        assert_eq!(epoch.to_string(), "1970-01-01 00:00:00");
+    }
+
+    // Test shift_by_units moving time_now in place with month clamping
+    #[test]
+    fn test_shift_by_units_months() {
+        let date_time = Local.with_ymd_and_hms(2023, 1, 31, 12, 0, 0).unwrap();
+        let mut easy_time = EasyTime::new_with_time(7, date_time);
+        easy_time.shift_by_units(2, TimeUnits::Months);
+        // January 31 + 2 months lands on March 31 without clamping
+        assert_eq!(
+            easy_time.time_now,
+            Local.with_ymd_and_hms(2023, 3, 31, 12, 0, 0).unwrap()
+        );
+        easy_time.shift_by_units(1, TimeUnits::Months);
+        // March 31 + 1 month clamps to April 30, the last day of April
+        assert_eq!(
+            easy_time.time_now,
+            Local.with_ymd_and_hms(2023, 4, 30, 12, 0, 0).unwrap()
+        );
+        assert_eq!(easy_time.value, 7);
+    }
+
+    // Test shift_by_units with a fixed-length unit and a negative value
+    #[test]
+    fn test_shift_by_units_hours() {
+        let date_time = Utc.with_ymd_and_hms(2023, 1, 31, 12, 0, 0).unwrap();
+        let mut easy_time = EasyTime::new_with_time(0, date_time);
+        easy_time.shift_by_units(-3, TimeUnits::Hours);
+        assert_eq!(easy_time.time_now, date_time - Duration::hours(3));
+    }
+
+    // Test months_since_year_zero across consecutive months and a year boundary
+    #[test]
+    fn test_months_since_year_zero() {
+        let at =
+            |y, m| EasyTime::new_with_time(0, Utc.with_ymd_and_hms(y, m, 15, 0, 0, 0).unwrap());
+        assert_eq!(at(2023, 1).months_since_year_zero(), 2023 * 12);
+        assert_eq!(
+            at(2023, 7).months_since_year_zero() - at(2023, 6).months_since_year_zero(),
+            1
+        );
+        assert_eq!(
+            at(2024, 1).months_since_year_zero() - at(2023, 12).months_since_year_zero(),
+            1
+        );
+    }
+
+    // Test weekday_abbr_localized with a German locale
+    #[cfg(feature = "unstable-locales")]
+    #[test]
+    fn test_weekday_abbr_localized() {
+        // 2023-10-02 is a Monday
+        let monday =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 10, 2, 9, 0, 0).unwrap());
+        assert_eq!(monday.weekday_abbr_localized(chrono::Locale::de_DE), "Mo");
+        assert_eq!(monday.weekday_abbr_localized(chrono::Locale::en_US), "Mon");
+    }
+
+    // Test clamp_into_future and clamp_into_past for Utc
+    #[test]
+    fn test_clamp_into_future_and_past_utc() {
+        let past = EasyTime::new_with_time(0, Utc::now() - Duration::hours(1));
+        let future = EasyTime::new_with_time(0, Utc::now() + Duration::hours(1));
+
+        let before = Utc::now();
+        let bumped = past.clamp_into_future();
+        assert!(bumped >= before && bumped <= Utc::now());
+        assert_eq!(future.clamp_into_future(), future.time_now);
+
+        assert_eq!(past.clamp_into_past(), past.time_now);
+        let before = Utc::now();
+        let pulled = future.clamp_into_past();
+        assert!(pulled >= before && pulled <= Utc::now());
+    }
+
+    // Test clamp_into_future and clamp_into_past for Local
+    #[test]
+    fn test_clamp_into_future_and_past_local() {
+        let past = EasyTime::new_with_time(0, Local::now() - Duration::days(1));
+        let future = EasyTime::new_with_time(0, Local::now() + Duration::days(1));
+        assert!(past.clamp_into_future() > past.time_now);
+        assert_eq!(future.clamp_into_future(), future.time_now);
+        assert_eq!(past.clamp_into_past(), past.time_now);
+        assert!(future.clamp_into_past() < future.time_now);
+    }
+
+    // Test same_time ignoring value, unlike ==
+    #[test]
+    fn test_same_time() {
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap();
+        let a = EasyTime::new_with_time(1, date_time);
+        let b = EasyTime::new_with_time(2, date_time);
+        let c = EasyTime::new_with_time(1, date_time + Duration::seconds(1));
+        assert!(a.same_time(&b));
+        assert_ne!(a, b);
+        assert!(!a.same_time(&c));
+    }
+
+    // Test step_with_remainder splitting 3h30m into whole hours and a remainder
+    #[test]
+    fn test_step_with_remainder() {
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 1, 8, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let total = Duration::hours(3) + Duration::minutes(30);
+        let (time, remainder) = easy_time.step_with_remainder(total, TimeUnits::Hours);
+        assert_eq!(time, date_time + Duration::hours(3));
+        assert_eq!(remainder, Duration::minutes(30));
+    }
+
+    // Test step_with_remainder rejecting calendar units
+    #[test]
+    #[should_panic(expected = "fixed-length unit")]
+    fn test_step_with_remainder_calendar_unit() {
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 10, 1, 8, 0, 0).unwrap());
+        easy_time.step_with_remainder(Duration::days(90), TimeUnits::Months);
+    }
+
+    // Test into_inner and the From conversion into DateTime
+    #[test]
+    fn test_into_inner() {
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(5, date_time);
+        assert_eq!(easy_time.clone().into_inner(), date_time);
+        let converted: DateTime<Utc> = easy_time.into();
+        assert_eq!(converted, date_time);
+    }
+
+    // Test countdown_iter stepping down by tick until zero
+    #[test]
+    fn test_countdown_iter() {
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let target = date_time + Duration::seconds(25);
+        let ticks: Vec<Duration> = easy_time
+            .countdown_iter(target, Duration::seconds(10))
+            .collect();
+        assert_eq!(
+            ticks,
+            vec![
+                Duration::seconds(25),
+                Duration::seconds(15),
+                Duration::seconds(5),
+                Duration::zero()
+            ]
+        );
+        let first_two: Vec<Duration> = easy_time
+            .countdown_iter(target, Duration::seconds(1))
+            .take(2)
+            .collect();
+        assert_eq!(first_two[0] - first_two[1], Duration::seconds(1));
+    }
+
+    // Test is_am and is_pm around noon and midnight
+    #[test]
+    fn test_is_am_is_pm() {
+        let at =
+            |h, m| EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 10, 1, h, m, 0).unwrap());
+        assert!(at(9, 0).is_am() && !at(9, 0).is_pm());
+        assert!(at(12, 0).is_pm() && !at(12, 0).is_am());
+        assert!(at(23, 59).is_pm());
+        assert!(at(0, 0).is_am());
+    }
+
+    // Test iso_week_of_month in July 2023, where the 1st is a Saturday
+    #[test]
+    fn test_iso_week_of_month() {
+        let at =
+            |m, d| EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, m, d, 12, 0, 0).unwrap());
+        // July 1–2 fall in the week whose Thursday is June 29, the 5th week of June
+        assert_eq!(at(7, 1).iso_week_of_month(), 5);
+        // The first Thursday of July is the 6th, so Monday July 3 starts week 1
+        assert_eq!(at(7, 3).iso_week_of_month(), 1);
+        assert_eq!(at(7, 9).iso_week_of_month(), 1);
+        assert_eq!(at(7, 20).iso_week_of_month(), 3);
+    }
+
+    // Test millenniums_from_now/ago saturating at chrono's supported year range
+    #[test]
+    fn test_millenniums_saturate_at_year_range() {
+        let date_time = Utc.with_ymd_and_hms(2023, 6, 15, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(3_000_000, date_time);
+        let future = easy_time.millenniums_from_now();
+        assert_eq!(future.year(), chrono::NaiveDate::MAX.year());
+        assert_eq!((future.month(), future.day()), (6, 15));
+        let past = easy_time.millenniums_ago();
+        assert_eq!(past.year(), chrono::NaiveDate::MIN.year());
+
+        let huge = EasyTime::new_with_time(i64::MAX, date_time);
+        assert_eq!(
+            huge.centuries_from_now().year(),
+            chrono::NaiveDate::MAX.year()
+        );
+        assert_eq!(huge.decades_ago().year(), chrono::NaiveDate::MIN.year());
+    }
+
+    // Test rata_die numbering and the from_rata_die round trip
+    #[test]
+    fn test_rata_die() {
+        let first_day = EasyTime::new_with_time(0, Utc.with_ymd_and_hms(1, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(first_day.rata_die(), 1);
+
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 1, 15, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let restored = EasyTime::from_rata_die(easy_time.rata_die()).unwrap();
+        assert_eq!(
+            restored.time_now,
+            Utc.with_ymd_and_hms(2023, 10, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(EasyTime::from_rata_die(i64::MAX), None);
+    }
+
+    // Test add_value and sub_value accumulating before applying a unit once
+    #[test]
+    fn test_add_and_sub_value() {
+        let date_time = Utc.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap();
+        let mut easy_time = EasyTime::new_with_time(3, date_time);
+        easy_time.add_value(5);
+        easy_time.sub_value(2);
+        assert_eq!(easy_time.value, 6);
+        assert_eq!(easy_time.days_from_now(), date_time + Duration::days(6));
+    }
+
+    // Test add_value and sub_value saturating at the i64 bounds
+    #[test]
+    fn test_add_and_sub_value_saturating() {
+        let mut easy_time = EasyTime::new_with_time(i64::MAX - 1, Utc::now());
+        easy_time.add_value(10);
+        assert_eq!(easy_time.value, i64::MAX);
+        easy_time.set_value(i64::MIN + 1);
+        easy_time.sub_value(10);
+        assert_eq!(easy_time.value, i64::MIN);
+    }
+
+    // Test iso8601_duration with the week designator and other units
+    #[test]
+    fn test_iso8601_duration() {
+        assert_eq!(EasyTime::iso8601_duration(3, TimeUnits::Weeks), "P3W");
+        assert_eq!(EasyTime::iso8601_duration(90, TimeUnits::Minutes), "PT90M");
+        assert_eq!(EasyTime::iso8601_duration(2, TimeUnits::Decades), "P20Y");
+        assert_eq!(EasyTime::iso8601_duration(-4, TimeUnits::Days), "-P4D");
+    }
+
+    // Test iso8601_duration_parts combining units and rejecting mixed weeks
+    #[test]
+    fn test_iso8601_duration_parts() {
+        assert_eq!(
+            EasyTime::iso8601_duration_parts(&[(1, TimeUnits::Days), (2, TimeUnits::Hours)]),
+            Ok("P1DT2H".to_string())
+        );
+        assert_eq!(
+            EasyTime::iso8601_duration_parts(&[(3, TimeUnits::Weeks)]),
+            Ok("P3W".to_string())
+        );
+        assert!(matches!(
+            EasyTime::iso8601_duration_parts(&[(3, TimeUnits::Weeks), (2, TimeUnits::Days)]),
+            Err(EasyTimeError::InvalidDuration(_))
+        ));
+    }
+
+    // Test is_valid_in_zone. America/New_York springs forward on 2023-03-12,
+    // skipping 02:00–03:00, and falls back on 2023-11-05, repeating 01:00–02:00.
+    #[test]
+    fn test_is_valid_in_zone() {
+        let new_york = chrono_tz::America::New_York;
+        let naive = |m, d, h, min| {
+            chrono::NaiveDate::from_ymd_opt(2023, m, d)
+                .unwrap()
+                .and_hms_opt(h, min, 0)
+                .unwrap()
+        };
+        assert!(EasyTime::is_valid_in_zone(naive(3, 12, 12, 0), &new_york));
+        assert!(!EasyTime::is_valid_in_zone(naive(3, 12, 2, 30), &new_york));
+        assert!(EasyTime::is_valid_in_zone(naive(11, 5, 1, 30), &new_york));
+        // Utc has no gaps at all
+        assert!(EasyTime::is_valid_in_zone(naive(3, 12, 2, 30), &Utc));
+    }
+
+    // Test to_military_time and to_military_time_zulu
+    #[test]
+    fn test_to_military_time() {
+        let afternoon =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 10, 1, 14, 30, 0).unwrap());
+        assert_eq!(afternoon.to_military_time(), "1430");
+        assert_eq!(afternoon.to_military_time_zulu(), "1430Z");
+        let midnight =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 10, 1, 0, 0, 0).unwrap());
+        assert_eq!(midnight.to_military_time(), "0000");
+
+        let tz = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        let local =
+            EasyTime::new_with_time(0, tz.with_ymd_and_hms(2023, 10, 1, 14, 30, 0).unwrap());
+        assert_eq!(local.to_military_time(), "1430");
+        assert_eq!(local.to_military_time_zulu(), "1230Z");
+    }
+
+    // Test next_dst_transition. The result depends on the machine's TZ: with
+    // TZ=America/New_York, starting 2023-02-01 finds the 2023-03-12 spring-forward
+    // at 07:00 UTC; in a zone without DST (e.g. UTC) there is no transition.
+    #[test]
+    fn test_next_dst_transition() {
+        let winter = Local.with_ymd_and_hms(2023, 1, 15, 12, 0, 0).unwrap();
+        let summer = Local.with_ymd_and_hms(2023, 7, 15, 12, 0, 0).unwrap();
+        let observes_dst = winter.offset() != summer.offset();
+
+        let start =
+            EasyTime::new_with_local(Local.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap(), 0);
+        match start.next_dst_transition() {
+            Some(transition) => {
+                assert!(observes_dst);
+                assert!(transition > start.time_now);
+                let before = transition - Duration::seconds(1);
+                assert_ne!(before.offset(), transition.offset());
+                assert!(!EasyTime::<Local>::range_crosses_dst(
+                    start.time_now,
+                    before
+                ));
+            }
+            None => assert!(!observes_dst),
+        }
+    }
+
+    // Test weeks_from_now and weeks_ago
+    #[test]
+    fn test_weeks_from_now_and_ago() {
+        let date_time = Local.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(2, date_time);
+        assert_eq!(easy_time.weeks_from_now(), date_time + Duration::days(14));
+        assert_eq!(easy_time.weeks_ago(), date_time - Duration::days(14));
+    }
+
+    // Test in_future and in_past dispatching on TimeUnits
+    #[test]
+    fn test_in_future_and_in_past() {
+        let date_time = Local.with_ymd_and_hms(2023, 1, 31, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(2, date_time);
+        assert_eq!(
+            easy_time.in_future(TimeUnits::Weeks),
+            easy_time.weeks_from_now()
+        );
+        assert_eq!(easy_time.in_past(TimeUnits::Weeks), easy_time.weeks_ago());
+        assert_eq!(
+            easy_time.in_future(TimeUnits::Months),
+            easy_time.months_from_now()
+        );
+        assert_eq!(
+            easy_time.in_past(TimeUnits::Decades),
+            easy_time.decades_ago()
+        );
+        assert_eq!(easy_time.in_past(TimeUnits::Hours), easy_time.hours_ago());
+    }
+
+    // Test duration_parts_labeled returning non-zero components largest first
+    #[test]
+    fn test_duration_parts_labeled() {
+        let parts = EasyTime::duration_parts_labeled(Duration::days(1) + Duration::hours(2));
+        assert_eq!(parts, vec![(1, "days"), (2, "hours")]);
+        let parts = EasyTime::duration_parts_labeled(Duration::minutes(61) + Duration::seconds(5));
+        assert_eq!(parts, vec![(1, "hours"), (1, "minutes"), (5, "seconds")]);
+        assert!(EasyTime::duration_parts_labeled(Duration::zero()).is_empty());
+    }
+
+    // Test truncate_subsecond_mut dropping nanoseconds in place
+    #[test]
+    fn test_truncate_subsecond_mut() {
+        let whole = Utc.with_ymd_and_hms(2023, 10, 1, 12, 0, 5).unwrap();
+        let mut easy_time = EasyTime::new_with_time(3, whole + Duration::nanoseconds(123_456_789));
+        assert_eq!(easy_time.truncate_subsecond(), whole);
+        easy_time.truncate_subsecond_mut();
+        assert_eq!(easy_time.time_now.nanosecond(), 0);
+        assert_eq!(easy_time.time_now, whole);
+        assert_eq!(easy_time.value, 3);
+    }
+
+    // Test sub-second offsets keep nanosecond precision
+    #[test]
+    fn test_sub_second_offsets() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 10, 1)
+            .unwrap()
+            .and_hms_nano_opt(12, 0, 0, 123_456_789)
+            .unwrap();
+        let date_time = Utc.from_utc_datetime(&naive);
+        let easy_time = EasyTime::new_with_time(5, date_time);
+        assert_eq!(easy_time.milliseconds_from_now().nanosecond(), 128_456_789);
+        assert_eq!(easy_time.milliseconds_ago().nanosecond(), 118_456_789);
+        assert_eq!(easy_time.microseconds_from_now().nanosecond(), 123_461_789);
+        assert_eq!(easy_time.microseconds_ago().nanosecond(), 123_451_789);
+        assert_eq!(easy_time.nanoseconds_from_now().nanosecond(), 123_456_794);
+        assert_eq!(easy_time.nanoseconds_ago().nanosecond(), 123_456_784);
+        assert_eq!(
+            easy_time.in_future(TimeUnits::Nanoseconds),
+            date_time + Duration::nanoseconds(5)
+        );
+        assert_eq!(
+            easy_time.in_past(TimeUnits::Milliseconds),
+            date_time - Duration::milliseconds(5)
+        );
+    }
+
+    // Test sub-second units in ISO 8601 durations
+    #[test]
+    fn test_iso8601_duration_sub_second() {
+        assert_eq!(
+            EasyTime::iso8601_duration(1500, TimeUnits::Milliseconds),
+            "PT1.5S"
+        );
+        assert_eq!(
+            EasyTime::iso8601_duration_parts(&[
+                (2, TimeUnits::Seconds),
+                (250, TimeUnits::Milliseconds)
+            ]),
+            Ok("PT2.25S".to_string())
+        );
+    }
+
+    // Test is_recent for Utc and Local
+    #[test]
+    fn test_is_recent() {
+        let window = Duration::minutes(5);
+        assert!(EasyTime::new_with_time(0, Utc::now() - Duration::minutes(2)).is_recent(window));
+        assert!(!EasyTime::new_with_time(0, Utc::now() - Duration::minutes(10)).is_recent(window));
+        assert!(EasyTime::new_with_time(0, Utc::now() + Duration::minutes(2)).is_recent(window));
+        assert!(EasyTime::new_with_local(Local::now() - Duration::minutes(2), 0).is_recent(window));
+        assert!(
+            !EasyTime::new_with_local(Local::now() - Duration::minutes(10), 0).is_recent(window)
+        );
+    }
+
+    // Test quarters_from_now reusing the month clamping logic
+    #[test]
+    fn test_quarters_from_now_clamps() {
+        let date_time = Local.with_ymd_and_hms(2023, 1, 31, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(1, date_time);
+        let expected = Local.with_ymd_and_hms(2023, 4, 30, 12, 0, 0).unwrap();
+        assert_eq!(easy_time.quarters_from_now(), expected);
+        assert_eq!(easy_time.in_future(TimeUnits::Quarters), expected);
+    }
+
+    // Test quarters_ago crossing a year boundary
+    #[test]
+    fn test_quarters_ago() {
+        let date_time = Local.with_ymd_and_hms(2023, 2, 28, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(1, date_time);
+        let expected = Local.with_ymd_and_hms(2022, 11, 28, 12, 0, 0).unwrap();
+        assert_eq!(easy_time.quarters_ago(), expected);
+        assert_eq!(easy_time.in_past(TimeUnits::Quarters), expected);
+        assert_eq!(EasyTime::iso8601_duration(2, TimeUnits::Quarters), "P6M");
+    }
+
+    // Test period_key for the common grouping units
+    #[test]
+    fn test_period_key() {
+        let date_time = Utc.with_ymd_and_hms(2023, 7, 15, 14, 5, 9).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        assert_eq!(easy_time.period_key(TimeUnits::Years), "2023");
+        assert_eq!(easy_time.period_key(TimeUnits::Months), "2023-07");
+        assert_eq!(easy_time.period_key(TimeUnits::Days), "2023-07-15");
+        assert_eq!(easy_time.period_key(TimeUnits::Hours), "2023-07-15T14");
+        assert_eq!(easy_time.period_key(TimeUnits::Quarters), "2023-Q3");
+        assert_eq!(easy_time.period_key(TimeUnits::Weeks), "2023-W28");
+    }
+
+    // Test period_key rejecting units without a sensible key
+    #[test]
+    #[should_panic(expected = "does not support")]
+    fn test_period_key_unsupported_unit() {
+        EasyTime::new_with_time(0, Utc::now()).period_key(TimeUnits::Centuries);
+    }
+
+    // Test try_months_from_now landing in the America/New_York spring-forward gap
+    #[test]
+    fn test_try_months_from_now_dst_gap() {
+        let new_york = chrono_tz::America::New_York;
+        let date_time = new_york.with_ymd_and_hms(2023, 2, 12, 2, 30, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(1, date_time);
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 3, 12)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        assert_eq!(
+            easy_time.try_months_from_now(),
+            Err(EasyTimeError::NonExistentLocalTime(naive))
+        );
+    }
+
+    // Test try_months_from_now landing on the America/New_York fall-back overlap
+    #[test]
+    fn test_try_months_from_now_dst_ambiguous() {
+        let new_york = chrono_tz::America::New_York;
+        let date_time = new_york.with_ymd_and_hms(2023, 10, 5, 1, 30, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(1, date_time);
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 11, 5)
+            .unwrap()
+            .and_hms_opt(1, 30, 0)
+            .unwrap();
+        assert_eq!(
+            easy_time.try_months_from_now(),
+            Err(EasyTimeError::AmbiguousLocalTime(naive))
+        );
+        // The panicking variant keeps resolving to the earlier (EDT) instant
+        let earliest = new_york.from_local_datetime(&naive).earliest().unwrap();
+        assert_eq!(easy_time.months_from_now(), earliest);
+    }
+
+    // Test try_years_ago succeeds when the target time is unambiguous
+    #[test]
+    fn test_try_years_ago() {
+        let date_time = Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(1, date_time);
+        let expected = Utc.with_ymd_and_hms(2023, 2, 28, 12, 0, 0).unwrap();
+        assert_eq!(easy_time.try_years_ago(), Ok(expected));
+    }
+
+    // Test months_from_now still panics on a DST gap
+    #[test]
+    #[should_panic(expected = "does not exist")]
+    fn test_months_from_now_dst_gap_panics() {
+        let new_york = chrono_tz::America::New_York;
+        let date_time = new_york.with_ymd_and_hms(2023, 2, 12, 2, 30, 0).unwrap();
+        EasyTime::new_with_time(1, date_time).months_from_now();
+    }
+
+    // Test sort_chronological ordering an unordered slice by instant
+    #[test]
+    fn test_sort_chronological() {
+        let base = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let mut items = vec![
+            EasyTime::new_with_time(1, base + Duration::hours(2)),
+            EasyTime::new_with_time(2, base),
+            EasyTime::new_with_time(3, base + Duration::hours(1)),
+        ];
+        assert!(!EasyTime::is_strictly_ascending(&items));
+        EasyTime::sort_chronological(&mut items);
+        let values: Vec<i64> = items.iter().map(|item| item.get_value()).collect();
+        assert_eq!(values, vec![2, 3, 1]);
+        assert!(EasyTime::is_strictly_ascending(&items));
+    }
+
+    // Test is_strictly_ascending rejecting a duplicate instant
+    #[test]
+    fn test_is_strictly_ascending_duplicate() {
+        let base = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let items = vec![
+            EasyTime::new_with_time(1, base),
+            EasyTime::new_with_time(2, base),
+            EasyTime::new_with_time(3, base + Duration::hours(1)),
+        ];
+        assert!(!EasyTime::is_strictly_ascending(&items));
+        assert!(EasyTime::<Utc>::is_strictly_ascending(&[]));
+    }
+
+    // Test adding a Duration shifts the time and keeps the value
+    #[test]
+    fn test_add_duration_operator() {
+        let date_time = Utc.with_ymd_and_hms(2023, 1, 1, 10, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(7, date_time);
+        let later = easy_time + Duration::hours(3);
+        assert_eq!(later.get_time(), date_time + Duration::hours(3));
+        assert_eq!(later.get_value(), 7);
+    }
+
+    // Test subtracting a Duration shifts the time and keeps the value
+    #[test]
+    fn test_sub_duration_operator() {
+        let date_time = Utc.with_ymd_and_hms(2023, 1, 1, 10, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(7, date_time);
+        let earlier = easy_time - Duration::minutes(90);
+        assert_eq!(earlier.get_time(), date_time - Duration::minutes(90));
+        assert_eq!(earlier.get_value(), 7);
+    }
+
+    // Test date_parts_utc/time_parts_utc projecting a +02:00 instant to UTC
+    #[test]
+    fn test_parts_utc() {
+        let offset = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        let date_time = offset.with_ymd_and_hms(2023, 6, 1, 1, 15, 30).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        assert_eq!(easy_time.date_parts(), (2023, 6, 1));
+        assert_eq!(easy_time.time_parts(), (1, 15, 30));
+        assert_eq!(easy_time.date_parts_utc(), (2023, 5, 31));
+        assert_eq!(easy_time.time_parts_utc(), (23, 15, 30));
+    }
+
+    // Test serde round-trip for EasyTime<Utc>
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip_utc() {
+        let date_time = Utc.with_ymd_and_hms(2023, 5, 17, 8, 30, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(42, date_time);
+        let json = serde_json::to_string(&easy_time).unwrap();
+        assert_eq!(json, r#"{"value":42,"time_now":"2023-05-17T08:30:00Z"}"#);
+        let parsed: EasyTime<Utc> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, easy_time);
+    }
+
+    // Test serde round-trip for EasyTime<Local>
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip_local() {
+        let easy_time = EasyTime::<Local>::new(-3);
+        let json = serde_json::to_string(&easy_time).unwrap();
+        let parsed: EasyTime<Local> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.get_value(), easy_time.get_value());
+        assert_eq!(parsed.get_time(), easy_time.get_time());
+    }
+
+    // Test with_millisecond replacing the sub-second part
+    #[test]
+    fn test_with_millisecond() {
+        let date_time = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 5).unwrap()
+            + Duration::nanoseconds(123_456_789);
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let expected =
+            Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 5).unwrap() + Duration::milliseconds(250);
+        assert_eq!(easy_time.with_millisecond(250), Ok(expected));
+    }
+
+    // Test with_millisecond rejecting a full second
+    #[test]
+    fn test_with_millisecond_out_of_range() {
+        let easy_time = EasyTime::new_with_time(0, Utc::now());
+        assert!(matches!(
+            easy_time.with_millisecond(1000),
+            Err(EasyTimeError::InvalidComponents(_))
+        ));
+    }
+
+    // Test humanize picking the largest unit with singular/plural wording
+    #[test]
+    fn test_humanize() {
+        let reference = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let humanize =
+            |offset: Duration| EasyTime::new_with_time(0, reference + offset).humanize(reference);
+        assert_eq!(humanize(Duration::seconds(-90)), "1 minute ago");
+        assert_eq!(humanize(Duration::zero()), "just now");
+        assert_eq!(humanize(Duration::minutes(-5)), "5 minutes ago");
+        assert_eq!(humanize(Duration::hours(-3)), "3 hours ago");
+        assert_eq!(humanize(Duration::days(2)), "in 2 days");
+        assert_eq!(humanize(Duration::days(1)), "in 1 day");
+        assert_eq!(humanize(Duration::days(95)), "in 3 months");
+    }
+
+    // Test elapsed_percent_of half way through an hour
+    #[test]
+    fn test_elapsed_percent_of_mid_hour() {
+        let date_time = Utc.with_ymd_and_hms(2023, 6, 1, 14, 30, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        assert!((easy_time.elapsed_percent_of(TimeUnits::Hours) - 50.0).abs() < 1e-9);
+        assert!((easy_time.elapsed_percent_of(TimeUnits::Days) - 14.5 / 24.0 * 100.0).abs() < 1e-9);
+    }
+
+    // Test elapsed_percent_of just after a period starts
+    #[test]
+    fn test_elapsed_percent_of_just_after_start() {
+        let date_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 1).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        assert!(easy_time.elapsed_percent_of(TimeUnits::Hours) < 0.1);
+        assert!(easy_time.elapsed_percent_of(TimeUnits::Years) < 0.001);
+        assert_eq!(easy_time.elapsed_percent_of(TimeUnits::Seconds), 0.0);
+    }
+
+    // Test fraction_through for calendar periods of uneven length
+    #[test]
+    fn test_fraction_through_calendar_units() {
+        let date_time = Utc.with_ymd_and_hms(2023, 2, 15, 0, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        assert!((easy_time.fraction_through(TimeUnits::Months) - 0.5).abs() < 1e-9);
+        // Wednesday noon is 2.5 days into a Monday-start week
+        let wednesday_noon = Utc.with_ymd_and_hms(2023, 6, 7, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, wednesday_noon);
+        assert!((easy_time.fraction_through(TimeUnits::Weeks) - 2.5 / 7.0).abs() < 1e-9);
+    }
+
+    // Test diff spanning 1 year, 2 months and 3 days
+    #[test]
+    fn test_diff_years_months_days() {
+        let start = EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2022, 1, 15, 8, 0, 0).unwrap());
+        let end = EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 3, 18, 10, 30, 5).unwrap());
+        let expected = TimeDiff {
+            years: 1,
+            months: 2,
+            days: 3,
+            hours: 2,
+            minutes: 30,
+            seconds: 5,
+            nanoseconds: 0,
+        };
+        assert_eq!(start.diff(&end), expected);
+        let negated = TimeDiff {
+            years: -1,
+            months: -2,
+            days: -3,
+            hours: -2,
+            minutes: -30,
+            seconds: -5,
+            nanoseconds: 0,
+        };
+        assert_eq!(end.diff(&start), negated);
+    }
+
+    // Test diff across a leap day counting February correctly
+    #[test]
+    fn test_diff_across_leap_day() {
+        let start = EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap());
+        let end = EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap());
+        // Jan 31 + 1 month clamps to Feb 29, leaving one day to Mar 1
+        let diff = start.diff(&end);
+        assert_eq!((diff.years, diff.months, diff.days), (0, 1, 1));
+        let start = EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2024, 2, 28, 0, 0, 0).unwrap());
+        assert_eq!(start.diff(&end).days, 2);
+    }
+
+    // Test next_aligned_minute within the hour and from an aligned minute
+    #[test]
+    fn test_next_aligned_minute() {
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 1, 1, 10, 7, 0).unwrap());
+        let expected = Utc.with_ymd_and_hms(2023, 1, 1, 10, 15, 0).unwrap();
+        assert_eq!(easy_time.next_aligned_minute(15), expected);
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 1, 1, 10, 15, 30).unwrap());
+        let expected = Utc.with_ymd_and_hms(2023, 1, 1, 10, 30, 0).unwrap();
+        assert_eq!(easy_time.next_aligned_minute(15), expected);
+    }
+
+    // Test next_aligned_minute rolling over into the next hour
+    #[test]
+    fn test_next_aligned_minute_hour_rollover() {
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 1, 1, 10, 58, 0).unwrap());
+        let expected = Utc.with_ymd_and_hms(2023, 1, 1, 11, 0, 0).unwrap();
+        assert_eq!(easy_time.next_aligned_minute(15), expected);
+        // 56 is the last multiple of 7 in the hour
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 12, 31, 23, 57, 0).unwrap());
+        let expected = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(easy_time.next_aligned_minute(7), expected);
+    }
+
+    // Test start_of_day and end_of_day time fields
+    #[test]
+    fn test_start_and_end_of_day() {
+        let date_time = Utc.with_ymd_and_hms(2023, 8, 9, 15, 42, 7).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let start = easy_time.start_of_day();
+        assert_eq!(
+            (
+                start.hour(),
+                start.minute(),
+                start.second(),
+                start.nanosecond()
+            ),
+            (0, 0, 0, 0)
+        );
+        assert_eq!(start.day(), 9);
+        let end = easy_time.end_of_day();
+        assert_eq!(
+            (end.hour(), end.minute(), end.second(), end.nanosecond()),
+            (23, 59, 59, 999_999_999)
+        );
+        assert_eq!(end.day(), 9);
+    }
+
+    // Test start_of_day on a date where midnight is skipped (Sao Paulo, 2018-11-04)
+    #[test]
+    fn test_start_of_day_midnight_gap() {
+        let sao_paulo = chrono_tz::America::Sao_Paulo;
+        let date_time = sao_paulo.with_ymd_and_hms(2018, 11, 4, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let expected = sao_paulo.with_ymd_and_hms(2018, 11, 4, 1, 0, 0).unwrap();
+        assert_eq!(easy_time.start_of_day(), expected);
+    }
+
+    // Test start_of_week on a week starting at a skipped midnight (Sao Paulo, 2018-11-04)
+    #[test]
+    fn test_start_of_week_midnight_gap() {
+        let sao_paulo = chrono_tz::America::Sao_Paulo;
+        let date_time = sao_paulo.with_ymd_and_hms(2018, 11, 7, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let expected = sao_paulo.with_ymd_and_hms(2018, 11, 4, 1, 0, 0).unwrap();
+        assert_eq!(easy_time.start_of_week(chrono::Weekday::Sun), expected);
+    }
+
+    // Test is_same_quarter comparing both year and quarter
+    #[test]
+    fn test_is_same_quarter() {
+        let at =
+            |y, m, d| EasyTime::new_with_time(0, Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap());
+        assert!(at(2023, 1, 5).is_same_quarter(&at(2023, 3, 31)));
+        assert!(!at(2023, 2, 1).is_same_quarter(&at(2024, 2, 1)));
+        assert!(!at(2023, 3, 31).is_same_quarter(&at(2023, 4, 1)));
+    }
+
+    // Test start_of_month and end_of_month for months of every length
+    #[test]
+    fn test_start_and_end_of_month() {
+        let month_bounds = |y, m| {
+            let easy_time =
+                EasyTime::new_with_time(0, Utc.with_ymd_and_hms(y, m, 10, 13, 0, 0).unwrap());
+            (easy_time.start_of_month(), easy_time.end_of_month())
+        };
+        let last_nano = |y, m, d| {
+            Utc.with_ymd_and_hms(y, m, d, 23, 59, 59).unwrap() + Duration::nanoseconds(999_999_999)
+        };
+        let (start, end) = month_bounds(2023, 1);
+        assert_eq!(start, Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(end, last_nano(2023, 1, 31));
+        assert_eq!(month_bounds(2024, 2).1, last_nano(2024, 2, 29));
+        assert_eq!(month_bounds(2023, 2).1, last_nano(2023, 2, 28));
+        assert_eq!(month_bounds(2023, 4).1, last_nano(2023, 4, 30));
+    }
+
+    // Test to_iso_date_with_zone for UTC and a fixed offset
+    #[test]
+    fn test_to_iso_date_with_zone() {
+        let utc = EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 10, 1, 23, 0, 0).unwrap());
+        assert_eq!(utc.to_iso_date_with_zone(), "2023-10-01Z");
+        let offset = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        let fixed =
+            EasyTime::new_with_time(0, offset.with_ymd_and_hms(2023, 10, 1, 0, 30, 0).unwrap());
+        assert_eq!(fixed.to_iso_date_with_zone(), "2023-10-01+02:00");
+    }
+
+    // Test start_of_year and end_of_year on a fixed local datetime
+    #[test]
+    fn test_start_and_end_of_year() {
+        let date_time = Local.with_ymd_and_hms(2023, 6, 15, 12, 30, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let start = easy_time.start_of_year();
+        assert_eq!((start.year(), start.month(), start.day()), (2023, 1, 1));
+        assert_eq!((start.hour(), start.minute(), start.second()), (0, 0, 0));
+        let end = easy_time.end_of_year();
+        assert_eq!((end.year(), end.month(), end.day()), (2023, 12, 31));
+        assert_eq!(
+            (end.hour(), end.minute(), end.second(), end.nanosecond()),
+            (23, 59, 59, 999_999_999)
+        );
+    }
+
+    // Test span_in for elapsed days and calendar months/years
+    #[test]
+    fn test_span_in() {
+        let at = |y, m, d, h| {
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap())
+        };
+        assert_eq!(
+            at(2023, 1, 1, 12).span_in(&at(2023, 1, 11, 11), TimeUnits::Days),
+            9
+        );
+        assert_eq!(
+            at(2023, 1, 11, 11).span_in(&at(2023, 1, 1, 12), TimeUnits::Days),
+            -9
+        );
+        assert_eq!(
+            at(2023, 1, 31, 0).span_in(&at(2023, 2, 28, 0), TimeUnits::Months),
+            1
+        );
+        assert_eq!(
+            at(2023, 1, 31, 0).span_in(&at(2023, 2, 27, 0), TimeUnits::Months),
+            0
+        );
+        assert_eq!(
+            at(2020, 2, 29, 0).span_in(&at(2023, 2, 28, 0), TimeUnits::Years),
+            3
+        );
+        assert_eq!(
+            at(2023, 6, 1, 0).span_in(&at(2020, 6, 2, 0), TimeUnits::Years),
+            -2
+        );
+    }
+
+    // Test weekday and is_weekend across a Monday–Sunday span
+    #[test]
+    fn test_weekday_and_is_weekend() {
+        use chrono::Weekday;
+        let monday = Utc.with_ymd_and_hms(2023, 10, 2, 9, 0, 0).unwrap();
+        let expected = [
+            (Weekday::Mon, false),
+            (Weekday::Tue, false),
+            (Weekday::Wed, false),
+            (Weekday::Thu, false),
+            (Weekday::Fri, false),
+            (Weekday::Sat, true),
+            (Weekday::Sun, true),
+        ];
+        for (offset, (weekday, weekend)) in expected.into_iter().enumerate() {
+            let easy_time = EasyTime::new_with_time(0, monday + Duration::days(offset as i64));
+            assert_eq!(easy_time.weekday(), weekday);
+            assert_eq!(easy_time.is_weekend(), weekend);
+        }
+    }
+
+    // Test from_naive_and_offset_minutes with IST (+05:30)
+    #[test]
+    fn test_from_naive_and_offset_minutes() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 4, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        let easy_time = EasyTime::from_naive_and_offset_minutes(naive, 330).unwrap();
+        assert_eq!(easy_time.get_time().offset().local_minus_utc(), 330 * 60);
+        assert_eq!(easy_time.get_time().naive_local(), naive);
+        assert_eq!(easy_time.to_iso_date_with_zone(), "2023-04-01+05:30");
+    }
+
+    // Test from_naive_and_offset_minutes rejecting an out-of-range offset
+    #[test]
+    fn test_from_naive_and_offset_minutes_invalid() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 4, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        assert!(EasyTime::from_naive_and_offset_minutes(naive, 5000).is_none());
+        assert!(EasyTime::from_naive_and_offset_minutes(naive, -1440).is_none());
+        assert!(EasyTime::from_naive_and_offset_minutes(naive, i32::MIN).is_none());
+    }
+
+    // Test next_weekday from a Wednesday, skipping today when it matches
+    #[test]
+    fn test_next_weekday() {
+        use chrono::Weekday;
+        let wednesday = Utc.with_ymd_and_hms(2023, 10, 4, 16, 45, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, wednesday);
+        assert_eq!(
+            easy_time.next_weekday(Weekday::Fri),
+            wednesday + Duration::days(2)
+        );
+        assert_eq!(
+            easy_time.next_weekday(Weekday::Wed),
+            wednesday + Duration::days(7)
+        );
+        assert_eq!(
+            easy_time.next_weekday(Weekday::Tue),
+            wednesday + Duration::days(6)
+        );
+    }
+
+    // Test previous_weekday from a Wednesday, skipping today when it matches
+    #[test]
+    fn test_previous_weekday() {
+        use chrono::Weekday;
+        let wednesday = Utc.with_ymd_and_hms(2023, 10, 4, 16, 45, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, wednesday);
+        assert_eq!(
+            easy_time.previous_weekday(Weekday::Mon),
+            wednesday - Duration::days(2)
+        );
+        assert_eq!(
+            easy_time.previous_weekday(Weekday::Wed),
+            wednesday - Duration::days(7)
+        );
+        assert_eq!(
+            easy_time.previous_weekday(Weekday::Thu),
+            wednesday - Duration::days(6)
+        );
+    }
+
+    // Test earliest_of and latest_of over a slice
+    #[test]
+    fn test_earliest_and_latest_of() {
+        let base = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let items = vec![
+            EasyTime::new_with_time(0, base + Duration::days(3)),
+            EasyTime::new_with_time(0, base - Duration::days(1)),
+            EasyTime::new_with_time(0, base + Duration::days(10)),
+        ];
+        assert_eq!(
+            EasyTime::earliest_of(&items),
+            Some(base - Duration::days(1))
+        );
+        assert_eq!(EasyTime::latest_of(&items), Some(base + Duration::days(10)));
+    }
+
+    // Test earliest_of and latest_of on an empty slice
+    #[test]
+    fn test_earliest_and_latest_of_empty() {
+        assert_eq!(EasyTime::<Utc>::earliest_of(&[]), None);
+        assert_eq!(EasyTime::<Utc>::latest_of(&[]), None);
+    }
+
+    // Test add_business_days moving forward over a weekend
+    #[test]
+    fn test_add_business_days_forward() {
+        let friday = Utc.with_ymd_and_hms(2023, 10, 6, 17, 30, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, friday);
+        assert_eq!(easy_time.add_business_days(1), friday + Duration::days(3));
+        assert_eq!(easy_time.add_business_days(5), friday + Duration::days(7));
+        assert_eq!(easy_time.add_business_days(6), friday + Duration::days(10));
+        assert_eq!(easy_time.add_business_days(0), friday);
+    }
+
+    // Test add_business_days moving backward over a weekend
+    #[test]
+    fn test_add_business_days_backward() {
+        let monday = Utc.with_ymd_and_hms(2023, 10, 9, 8, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, monday);
+        assert_eq!(easy_time.add_business_days(-1), monday - Duration::days(3));
+        assert_eq!(
+            easy_time.add_business_days(-10),
+            monday - Duration::days(14)
+        );
+    }
+
+    // Test add_business_days starting on a weekend
+    #[test]
+    fn test_add_business_days_from_weekend() {
+        let saturday = Utc.with_ymd_and_hms(2023, 10, 7, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, saturday);
+        assert_eq!(easy_time.add_business_days(1), saturday + Duration::days(2));
+        assert_eq!(easy_time.add_business_days(5), saturday + Duration::days(6));
+        assert_eq!(
+            easy_time.add_business_days(-1),
+            saturday - Duration::days(1)
+        );
+    }
+
+    // Test to_tm following the C struct tm conventions
+    #[test]
+    fn test_to_tm() {
+        // 2023-03-05 was a Sunday, the 64th day of the year
+        let date_time = Utc.with_ymd_and_hms(2023, 3, 5, 7, 8, 9).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let expected = Tm {
+            tm_sec: 9,
+            tm_min: 8,
+            tm_hour: 7,
+            tm_mday: 5,
+            tm_mon: 2,
+            tm_year: 123,
+            tm_wday: 0,
+            tm_yday: 63,
+        };
+        assert_eq!(easy_time.to_tm(), expected);
+    }
+
+    // Test fortnights_from_now/fortnights_ago as a 14-day change
+    #[test]
+    fn test_fortnights() {
+        let date_time = Utc.with_ymd_and_hms(2023, 6, 2, 9, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(1, date_time);
+        assert_eq!(
+            easy_time.fortnights_from_now(),
+            date_time + Duration::days(14)
+        );
+        assert_eq!(easy_time.fortnights_ago(), date_time - Duration::days(14));
+        assert_eq!(
+            easy_time.in_future(TimeUnits::Fortnights),
+            date_time + Duration::days(14)
+        );
+        assert_eq!(
+            easy_time.in_past(TimeUnits::Fortnights),
+            date_time - Duration::days(14)
+        );
+        assert_eq!(EasyTime::iso8601_duration(3, TimeUnits::Fortnights), "P6W");
+    }
+
+    // Test from_tm round-tripping through to_tm
+    #[test]
+    fn test_from_tm_round_trip() {
+        let date_time = Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 58).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let tm = easy_time.to_tm();
+        assert_eq!(EasyTime::from_tm(&tm), Ok(EasyTime::from_time(date_time)));
+        // Weekday and day of year are recomputed, not trusted
+        let misleading = Tm {
+            tm_wday: 6,
+            tm_yday: 0,
+            ..tm
+        };
+        assert_eq!(EasyTime::from_tm(&misleading).unwrap().to_tm(), tm);
+    }
+
+    // Test from_tm rejecting fields outside the C ranges
+    #[test]
+    fn test_from_tm_invalid() {
+        let tm = Tm {
+            tm_mday: 31,
+            tm_mon: 3,
+            tm_year: 123,
+            ..Tm::default()
+        };
+        assert!(matches!(
+            EasyTime::from_tm(&tm),
+            Err(EasyTimeError::InvalidComponents(_))
+        ));
+        let negative = Tm {
+            tm_mday: 1,
+            tm_hour: -1,
+            ..Tm::default()
+        };
+        assert!(EasyTime::from_tm(&negative).is_err());
+    }
+
+    // Test parse_rfc3339 with a UTC string
+    #[test]
+    fn test_parse_rfc3339() {
+        let easy_time = EasyTime::parse_rfc3339("2023-10-01T14:30:00Z", 5).unwrap();
+        assert_eq!(easy_time.get_value(), 5);
+        assert_eq!(
+            easy_time.get_time(),
+            Utc.with_ymd_and_hms(2023, 10, 1, 14, 30, 0).unwrap()
+        );
+    }
+
+    // Test parse_rfc3339 normalizing a non-UTC offset to UTC
+    #[test]
+    fn test_parse_rfc3339_offset() {
+        let easy_time = EasyTime::parse_rfc3339("2023-10-01T14:30:00+02:00", 0).unwrap();
+        assert_eq!(
+            easy_time.get_time(),
+            Utc.with_ymd_and_hms(2023, 10, 1, 12, 30, 0).unwrap()
+        );
+    }
+
+    // Test parse_rfc3339 rejecting a malformed string
+    #[test]
+    fn test_parse_rfc3339_malformed() {
+        assert!(matches!(
+            EasyTime::parse_rfc3339("2023-10-01 14:30", 0),
+            Err(EasyTimeError::ParseError(_))
+        ));
+    }
+
+    // Test unix_duration for an instant after the epoch
+    #[test]
+    fn test_unix_duration() {
+        let date_time =
+            Utc.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap() + Duration::milliseconds(250);
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        assert_eq!(
+            easy_time.unix_duration(),
+            Ok(std::time::Duration::new(1_696_161_600, 250_000_000))
+        );
+    }
+
+    // Test unix_duration rejecting an instant before the epoch
+    #[test]
+    fn test_unix_duration_pre_epoch() {
+        let date_time = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        assert_eq!(
+            easy_time.unix_duration(),
+            Err(EasyTimeError::TimestampOutOfRange(-1))
+        );
+    }
+
+    // Test parse_with_format with a custom day-first layout
+    #[test]
+    fn test_parse_with_format() {
+        let easy_time =
+            EasyTime::parse_with_format("25/12/2023 18:45", "%d/%m/%Y %H:%M", 2).unwrap();
+        assert_eq!(easy_time.get_value(), 2);
+        assert_eq!(
+            easy_time.get_time(),
+            Utc.with_ymd_and_hms(2023, 12, 25, 18, 45, 0).unwrap()
+        );
+        assert!(matches!(
+            EasyTime::parse_with_format("12/25/2023 18:45", "%d/%m/%Y %H:%M", 0),
+            Err(EasyTimeError::ParseError(_))
+        ));
+    }
+
+    // Test parse_with_format_local keeping the local wall-clock fields
+    #[test]
+    fn test_parse_with_format_local() {
+        let easy_time =
+            EasyTime::parse_with_format_local("25/12/2023 18:45", "%d/%m/%Y %H:%M", 0).unwrap();
+        let expected = Local.with_ymd_and_hms(2023, 12, 25, 18, 45, 0).unwrap();
+        assert_eq!(easy_time.get_time(), expected);
+        assert!(EasyTime::parse_with_format_local("not a date", "%d/%m/%Y %H:%M", 0).is_err());
+    }
+
+    // Test align_range snapping a range outward to whole hours
+    #[test]
+    fn test_align_range_hours() {
+        let start = Utc.with_ymd_and_hms(2023, 5, 1, 10, 15, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2023, 5, 1, 11, 45, 0).unwrap();
+        let expected = (
+            Utc.with_ymd_and_hms(2023, 5, 1, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 5, 1, 12, 0, 0).unwrap(),
+        );
+        assert_eq!(
+            EasyTime::align_range(start, end, TimeUnits::Hours),
+            expected
+        );
+    }
+
+    // Test align_range leaving boundaries that are already aligned
+    #[test]
+    fn test_align_range_already_aligned() {
+        let start = Utc.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2023, 7, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            EasyTime::align_range(start, end, TimeUnits::Months),
+            (start, end)
+        );
+        let mid = Utc.with_ymd_and_hms(2023, 7, 1, 0, 0, 1).unwrap();
+        let next = Utc.with_ymd_and_hms(2023, 8, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            EasyTime::align_range(start, mid, TimeUnits::Months),
+            (start, next)
+        );
+    }
+
+    // Test FromStr accepting RFC 3339 and the default layout
+    #[test]
+    fn test_from_str() {
+        let expected = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let easy_time: EasyTime<Utc> = "2024-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(easy_time, EasyTime::new_with_time(0, expected));
+        let easy_time: EasyTime<Utc> = "2024-01-01 00:00:00".parse().unwrap();
+        assert_eq!(easy_time, EasyTime::new_with_time(0, expected));
+    }
+
+    // Test FromStr rejecting an unrecognized layout
+    #[test]
+    fn test_from_str_invalid() {
+        let result: Result<EasyTime<Utc>, _> = "01/01/2024".parse();
+        assert!(matches!(result, Err(EasyTimeError::ParseError(_))));
+    }
+
+    // Test seconds_to_clock within and beyond a single day
+    #[test]
+    fn test_seconds_to_clock() {
+        assert_eq!(EasyTime::seconds_to_clock(43_200), Some((12, 0, 0)));
+        assert_eq!(EasyTime::seconds_to_clock(86_399), Some((23, 59, 59)));
+        assert_eq!(EasyTime::seconds_to_clock(3_723), Some((1, 2, 3)));
+        assert_eq!(EasyTime::seconds_to_clock(90_000), None);
+    }
+
+    // Test to_naive and to_naive_utc differing by the offset
+    #[test]
+    fn test_to_naive_and_to_naive_utc() {
+        let offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        let date_time = offset.with_ymd_and_hms(2023, 3, 1, 21, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let local = chrono::NaiveDate::from_ymd_opt(2023, 3, 1)
+            .unwrap()
+            .and_hms_opt(21, 0, 0)
+            .unwrap();
+        assert_eq!(easy_time.to_naive(), local);
+        assert_eq!(easy_time.to_naive_utc(), local + Duration::hours(5));
+    }
+
+    // Test last_completed_boundary from the middle of an hour
+    #[test]
+    fn test_last_completed_boundary_mid_hour() {
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 5, 1, 10, 30, 0).unwrap());
+        let expected = Utc.with_ymd_and_hms(2023, 5, 1, 10, 0, 0).unwrap();
+        assert_eq!(
+            easy_time.last_completed_boundary(TimeUnits::Hours),
+            expected
+        );
+    }
+
+    // Test last_completed_boundary skipping a boundary equal to time_now
+    #[test]
+    fn test_last_completed_boundary_on_boundary() {
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 5, 1, 10, 0, 0).unwrap());
+        let expected = Utc.with_ymd_and_hms(2023, 5, 1, 9, 0, 0).unwrap();
+        assert_eq!(
+            easy_time.last_completed_boundary(TimeUnits::Hours),
+            expected
+        );
+        let expected = Utc.with_ymd_and_hms(2023, 4, 30, 0, 0, 0).unwrap();
+        let midnight =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap());
+        assert_eq!(midnight.last_completed_boundary(TimeUnits::Days), expected);
+    }
+
+    // Test to_utc and to_local keeping the instant and value
+    #[test]
+    fn test_to_utc_and_to_local() {
+        let date_time = Local.with_ymd_and_hms(2023, 7, 4, 15, 0, 0).unwrap();
+        let local = EasyTime::new_with_time(9, date_time);
+        let utc = local.to_utc();
+        assert_eq!(utc.get_time().timestamp(), date_time.timestamp());
+        assert_eq!(utc.get_value(), 9);
+        let back = utc.to_local();
+        assert_eq!(back.get_time(), date_time);
+        assert_eq!(back.get_time().naive_local(), date_time.naive_local());
+        assert_eq!(back.get_value(), 9);
+    }
+
+    // Test iter_months yielding each month start in the range
+    #[test]
+    fn test_iter_months() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 20, 15, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2023, 4, 2, 8, 0, 0).unwrap();
+        let months: Vec<_> = EasyTime::new_with_time(0, start).iter_months(end).collect();
+        let expected: Vec<_> = (1..=4)
+            .map(|month| Utc.with_ymd_and_hms(2023, month, 1, 0, 0, 0).unwrap())
+            .collect();
+        assert_eq!(months, expected);
+        assert_eq!(
+            EasyTime::new_with_time(0, end).iter_months(start).count(),
+            0
+        );
+    }
+
+    // Test with_timezone converting to a FixedOffset without moving the instant
+    #[test]
+    fn test_with_timezone_fixed_offset() {
+        let date_time = Utc.with_ymd_and_hms(2023, 9, 1, 22, 30, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(4, date_time);
+        let offset = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+        let tokyo = easy_time.with_timezone(&offset);
+        assert_eq!(tokyo.get_time(), date_time);
+        assert_eq!(tokyo.get_value(), 4);
+        assert_eq!(tokyo.to_date_time(), "2023-09-02 07:30:00");
+    }
+
+    // Test cmp_by_date ignoring the time of day
+    #[test]
+    fn test_cmp_by_date() {
+        use std::cmp::Ordering;
+        let morning =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 5, 1, 6, 0, 0).unwrap());
+        let evening =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 5, 1, 22, 0, 0).unwrap());
+        let next_day =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 5, 2, 1, 0, 0).unwrap());
+        assert_eq!(morning.cmp_by_date(&evening), Ordering::Equal);
+        assert_eq!(evening.cmp_by_date(&next_day), Ordering::Less);
+        assert_eq!(next_day.cmp_by_date(&morning), Ordering::Greater);
+    }
+
+    // Test truncate zeroing components finer than the unit
+    #[test]
+    fn test_truncate() {
+        let date_time =
+            Utc.with_ymd_and_hms(2023, 5, 17, 10, 42, 37).unwrap() + Duration::milliseconds(500);
+        let easy_time = EasyTime::new_with_time(0, date_time);
+        let expected = Utc.with_ymd_and_hms(2023, 5, 17, 10, 42, 0).unwrap();
+        assert_eq!(easy_time.truncate(TimeUnits::Minutes), expected);
+        let expected = Utc.with_ymd_and_hms(2023, 5, 17, 10, 0, 0).unwrap();
+        assert_eq!(easy_time.truncate(TimeUnits::Hours), expected);
+        let expected = Utc.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap();
+        assert_eq!(easy_time.truncate(TimeUnits::Months), expected);
+    }
+
+    // Test round going up from half a unit and down below it
+    #[test]
+    fn test_round() {
+        let at =
+            |s| EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 5, 17, 10, 15, s).unwrap());
+        let up = Utc.with_ymd_and_hms(2023, 5, 17, 10, 16, 0).unwrap();
+        let down = Utc.with_ymd_and_hms(2023, 5, 17, 10, 15, 0).unwrap();
+        assert_eq!(at(30).round(TimeUnits::Minutes), up);
+        assert_eq!(at(45).round(TimeUnits::Minutes), up);
+        assert_eq!(at(29).round(TimeUnits::Minutes), down);
+        let hour = Utc.with_ymd_and_hms(2023, 5, 17, 10, 0, 0).unwrap();
+        assert_eq!(at(0).round(TimeUnits::Hours), hour);
+    }
+
+    // Test epoch_if_in_zone reinterpreting noon in a +02:00 zone
+    #[test]
+    fn test_epoch_if_in_zone() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 6, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let offset = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        let utc_noon = naive.and_utc().timestamp();
+        assert_eq!(
+            EasyTime::epoch_if_in_zone(naive, &offset),
+            Ok(utc_noon - 2 * 3600)
+        );
+        assert_eq!(EasyTime::epoch_if_in_zone(naive, &Utc), Ok(utc_noon));
+    }
+
+    // Test epoch_if_in_zone rejecting a time in a DST gap
+    #[test]
+    fn test_epoch_if_in_zone_dst_gap() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 3, 12)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        assert_eq!(
+            EasyTime::epoch_if_in_zone(naive, &chrono_tz::America::New_York),
+            Err(EasyTimeError::NonExistentLocalTime(naive))
+        );
+    }
+
+    // Test range stepping by two days across a ten-day span
+    #[test]
+    fn test_range_by_days() {
+        let start = Utc.with_ymd_and_hms(2023, 5, 1, 9, 0, 0).unwrap();
+        let end = start + Duration::days(10);
+        let items: Vec<_> = EasyTime::range(start, end, 2, TimeUnits::Days).collect();
+        assert_eq!(items.len(), 5);
+        assert_eq!(items[0], start);
+        assert_eq!(items[4], start + Duration::days(8));
+    }
+
+    // Test range with calendar steps and non-positive steps
+    #[test]
+    fn test_range_edge_cases() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2023, 4, 1, 0, 0, 0).unwrap();
+        let months: Vec<_> = EasyTime::range(start, end, 1, TimeUnits::Months).collect();
+        let expected = vec![
+            start,
+            Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap(),
+        ];
+        assert_eq!(months, expected);
+        assert_eq!(EasyTime::range(start, end, 0, TimeUnits::Days).count(), 0);
+        assert_eq!(EasyTime::range(start, end, -1, TimeUnits::Days).count(), 0);
+    }
+
+    // Test to_relative_with_labels using Spanish wording
+    #[test]
+    fn test_to_relative_with_labels_spanish() {
+        let unit = |one: &str, other: &str| (one.to_string(), other.to_string());
+        let spanish = RelativeLabels {
+            just_now: "ahora mismo".to_string(),
+            past: "hace {}".to_string(),
+            future: "dentro de {}".to_string(),
+            seconds: unit("{n} segundo", "{n} segundos"),
+            minutes: unit("{n} minuto", "{n} minutos"),
+            hours: unit("{n} hora", "{n} horas"),
+            days: unit("{n} día", "{n} días"),
+            weeks: unit("{n} semana", "{n} semanas"),
+            months: unit("{n} mes", "{n} meses"),
+            years: unit("{n} año", "{n} años"),
+        };
+        let reference = Utc.with_ymd_and_hms(2023, 6, 10, 12, 0, 0).unwrap();
+        let relative = |offset: Duration| {
+            EasyTime::new_with_time(0, reference + offset)
+                .to_relative_with_labels(reference, &spanish)
+        };
+        assert_eq!(relative(Duration::days(-3)), "hace 3 días");
+        assert_eq!(relative(Duration::hours(1)), "dentro de 1 hora");
+        assert_eq!(relative(Duration::seconds(10)), "ahora mismo");
+    }
+
+    // Test the English default labels match humanize
+    #[test]
+    fn test_relative_labels_default() {
+        let reference = Utc.with_ymd_and_hms(2023, 6, 10, 12, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, reference - Duration::days(3));
+        let labels = RelativeLabels::default();
+        assert_eq!(
+            easy_time.to_relative_with_labels(reference, &labels),
+            "3 days ago"
+        );
+        assert_eq!(easy_time.humanize(reference), "3 days ago");
+    }
+
+    // Test sorting EasyTime by time_now, then value
+    #[test]
+    fn test_ord_sorts_by_time_then_value() {
+        let base = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let mut items = vec![
+            EasyTime::new_with_time(1, base + Duration::hours(1)),
+            EasyTime::new_with_time(5, base),
+            EasyTime::new_with_time(2, base),
+        ];
+        items.sort();
+        let expected = vec![
+            EasyTime::new_with_time(2, base),
+            EasyTime::new_with_time(5, base),
+            EasyTime::new_with_time(1, base + Duration::hours(1)),
+        ];
+        assert_eq!(items, expected);
+        assert!(items[0] < items[1]);
+    }
+
+    // Test ordering and equality for Local instances
+    #[test]
+    fn test_ord_local() {
+        let date_time = Local.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let earlier = EasyTime::new_with_time(0, date_time);
+        let later = EasyTime::new_with_time(0, date_time + Duration::seconds(1));
+        assert!(earlier < later);
+        assert_eq!(earlier.clone().max(later.clone()), later);
+        assert_eq!(earlier, EasyTime::new_with_time(0, date_time));
+    }
+
+    // Test count_days_where counting Mondays over several weeks
+    #[test]
+    fn test_count_days_where_mondays() {
+        use chrono::Weekday;
+        // Sunday 2023-10-01 through Monday 2023-10-30 covers five Mondays
+        let start = Utc.with_ymd_and_hms(2023, 10, 1, 18, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2023, 10, 30, 6, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(0, start);
+        assert_eq!(
+            easy_time.count_days_where(end, |date| date.weekday() == Weekday::Mon),
+            5
+        );
+        assert_eq!(easy_time.count_days_where(end, |_| true), 30);
+        assert_eq!(
+            EasyTime::new_with_time(0, end).count_days_where(start, |_| true),
+            0
+        );
+    }
+
+    // Test Hash deduplicating equal instances in a HashSet
+    #[test]
+    fn test_hash_set_deduplicates() {
+        use std::collections::HashSet;
+        let date_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let mut set = HashSet::new();
+        set.insert(EasyTime::new_with_time(3, date_time));
+        set.insert(EasyTime::new_with_time(3, date_time));
+        assert_eq!(set.len(), 1);
+        set.insert(EasyTime::new_with_time(4, date_time));
+        set.insert(EasyTime::new_with_time(
+            3,
+            date_time + Duration::nanoseconds(1),
+        ));
+        assert_eq!(set.len(), 3);
+    }
+
+    // Test months_between only counting months whose day has been reached
+    #[test]
+    fn test_months_between() {
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 1, 15, 0, 0, 0).unwrap());
+        assert_eq!(
+            easy_time.months_between(Utc.with_ymd_and_hms(2023, 3, 10, 0, 0, 0).unwrap()),
+            1
+        );
+        assert_eq!(
+            easy_time.months_between(Utc.with_ymd_and_hms(2023, 3, 20, 0, 0, 0).unwrap()),
+            2
+        );
+        assert_eq!(
+            easy_time.months_between(Utc.with_ymd_and_hms(2022, 11, 20, 0, 0, 0).unwrap()),
+            -1
+        );
+        assert_eq!(
+            easy_time.months_between(Utc.with_ymd_and_hms(2022, 11, 10, 0, 0, 0).unwrap()),
+            -2
+        );
+    }
+
+    // Test months_between clamping like span_in at the end of a short month
+    #[test]
+    fn test_months_between_end_of_month() {
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap());
+        let end = Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap();
+        assert_eq!(easy_time.months_between(end), 1);
+        assert_eq!(
+            easy_time.months_between(end),
+            easy_time.span_in(&EasyTime::new_with_time(0, end), TimeUnits::Months)
+        );
+        assert_eq!(
+            easy_time.months_between(Utc.with_ymd_and_hms(2023, 2, 27, 0, 0, 0).unwrap()),
+            0
+        );
+    }
+
+    // Test years_between before and after the anniversary
+    #[test]
+    fn test_years_between() {
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(1990, 6, 15, 0, 0, 0).unwrap());
+        assert_eq!(
+            easy_time.years_between(Utc.with_ymd_and_hms(2023, 6, 15, 0, 0, 0).unwrap()),
+            33
+        );
+        assert_eq!(
+            easy_time.years_between(Utc.with_ymd_and_hms(2023, 6, 14, 0, 0, 0).unwrap()),
+            32
+        );
+        assert_eq!(
+            easy_time.years_between(Utc.with_ymd_and_hms(1989, 6, 16, 0, 0, 0).unwrap()),
+            0
+        );
+        assert_eq!(
+            easy_time.years_between(Utc.with_ymd_and_hms(1989, 6, 15, 0, 0, 0).unwrap()),
+            -1
+        );
+    }
+
+    // Test years_between from a leap day reaching its anniversary on Feb 28
+    #[test]
+    fn test_years_between_leap_day() {
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2020, 2, 29, 0, 0, 0).unwrap());
+        assert_eq!(
+            easy_time.years_between(Utc.with_ymd_and_hms(2021, 2, 27, 0, 0, 0).unwrap()),
+            0
+        );
+        assert_eq!(
+            easy_time.years_between(Utc.with_ymd_and_hms(2021, 2, 28, 0, 0, 0).unwrap()),
+            1
+        );
+        assert_eq!(
+            easy_time.years_between(Utc.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap()),
+            1
+        );
+        assert_eq!(
+            easy_time.years_between(Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap()),
+            4
+        );
+    }
+
+    // Test min_utc and max_utc bracketing realistic dates
+    #[test]
+    fn test_min_and_max_utc() {
+        let min = EasyTime::min_utc(1);
+        let max = EasyTime::max_utc(2);
+        let ancient = Utc.with_ymd_and_hms(-9999, 1, 1, 0, 0, 0).unwrap();
+        let far_future = Utc.with_ymd_and_hms(9999, 12, 31, 23, 59, 59).unwrap();
+        assert!(min.get_time() < ancient);
+        assert!(max.get_time() > far_future);
+        assert!(min < EasyTime::new_with_utc(1));
+        assert_eq!((min.get_value(), max.get_value()), (1, 2));
+    }
+
+    // Test format_localized with French month names
+    #[cfg(feature = "unstable-locales")]
+    #[test]
+    fn test_format_localized_french() {
+        let easy_time =
+            EasyTime::new_with_time(0, Utc.with_ymd_and_hms(2023, 7, 14, 9, 0, 0).unwrap());
+        assert_eq!(
+            easy_time.format_localized("%d %B %Y", chrono::Locale::fr_FR),
+            "14 juillet 2023"
+        );
+        assert_eq!(
+            easy_time.format_localized("%A", chrono::Locale::fr_FR),
+            "vendredi"
+        );
+    }
+
+    // Test dates_until yielding an inclusive run of days in both directions
+    #[test]
+    fn test_dates_until() {
+        let start = Utc.with_ymd_and_hms(2023, 3, 1, 8, 30, 0).unwrap();
+        let target = Utc.with_ymd_and_hms(2023, 3, 6, 1, 0, 0).unwrap();
+        let forward: Vec<_> = EasyTime::new_with_time(0, start)
+            .dates_until(target)
+            .collect();
+        assert_eq!(forward.len(), 6);
+        assert_eq!(forward[0], start);
+        assert_eq!(forward[5], start + Duration::days(5));
+        let backward: Vec<_> = EasyTime::new_with_time(0, target)
+            .dates_until(start)
+            .collect();
+        assert_eq!(backward.len(), 6);
+        assert_eq!(
+            backward[5],
+            Utc.with_ymd_and_hms(2023, 3, 1, 1, 0, 0).unwrap()
+        );
+        assert_eq!(
+            EasyTime::new_with_time(0, start).dates_until(start).count(),
+            1
+        );
+    }
+
+    // Test parse_flexible accepting RFC 3339, date-time and date-only shapes
+    #[test]
+    fn test_parse_flexible_shapes_with_value() {
+        let rfc3339 = EasyTime::parse_flexible("2024-01-01T12:00:00Z", 7).unwrap();
+        let date_time = EasyTime::parse_flexible("2024-01-01 12:00:00", 7).unwrap();
+        let date_only = EasyTime::parse_flexible("2024-01-01", 7).unwrap();
+        let noon = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(rfc3339, EasyTime::new_with_time(7, noon));
+        assert_eq!(date_time, EasyTime::new_with_time(7, noon));
+        let midnight = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(date_only, EasyTime::new_with_time(7, midnight));
+        assert!(EasyTime::parse_flexible("2024-13-01", 7).is_err());
+    }
+
+    // Test fortnight offsets saturating instead of overflowing
+    #[test]
+    fn test_fortnights_saturate() {
+        let date_time = Utc.with_ymd_and_hms(2023, 6, 2, 9, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(i64::MAX / 2, date_time);
+        assert_eq!(easy_time.fortnights_from_now(), DateTime::<Utc>::MAX_UTC);
+        assert_eq!(easy_time.fortnights_ago(), DateTime::<Utc>::MIN_UTC);
+        assert_eq!(
+            easy_time.in_future(TimeUnits::Fortnights),
+            DateTime::<Utc>::MAX_UTC
+        );
+        assert_eq!(
+            easy_time.in_past(TimeUnits::Fortnights),
+            DateTime::<Utc>::MIN_UTC
+        );
+        let mut checked = EasyTime::new_with_time(0, date_time);
+        assert!(checked
+            .set_value_checked(i64::MAX / 2, TimeUnits::Fortnights)
+            .is_err());
+        assert_eq!(checked.set_value_checked(26, TimeUnits::Fortnights), Ok(()));
+    }
+
+    // Test truncate_subsecond keeping the offset of a repeated fall-back hour
+    #[test]
+    fn test_truncate_subsecond_ambiguous_hour() {
+        let new_york = chrono_tz::America::New_York;
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 11, 5)
+            .unwrap()
+            .and_hms_milli_opt(1, 30, 0, 500)
+            .unwrap();
+        let time = new_york.from_local_datetime(&naive).latest().unwrap();
+        let mut easy_time = EasyTime::new_with_time(0, time);
+        let truncated = easy_time.truncate_subsecond();
+        assert_eq!(time - truncated, Duration::milliseconds(500));
+        assert_eq!(truncated.offset().to_string(), "EST");
+        easy_time.truncate_subsecond_mut();
+        assert_eq!(easy_time.get_time(), truncated);
+    }
+
+    // Test next_aligned_minute staying after time_now in a repeated fall-back hour
+    #[test]
+    fn test_next_aligned_minute_ambiguous_hour() {
+        let new_york = chrono_tz::America::New_York;
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 11, 5)
+            .unwrap()
+            .and_hms_milli_opt(1, 30, 0, 500)
+            .unwrap();
+        let time = new_york.from_local_datetime(&naive).latest().unwrap();
+        let next = EasyTime::new_with_time(0, time).next_aligned_minute(15);
+        assert!(next > time);
+        assert_eq!(
+            next - time,
+            Duration::minutes(15) - Duration::milliseconds(500)
+        );
+    }
+
+    // Test with_millisecond on both instants of a repeated fall-back hour
+    #[test]
+    fn test_with_millisecond_ambiguous_hour() {
+        let new_york = chrono_tz::America::New_York;
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 11, 5)
+            .unwrap()
+            .and_hms_milli_opt(1, 30, 0, 500)
+            .unwrap();
+        for time in [
+            new_york.from_local_datetime(&naive).earliest().unwrap(),
+            new_york.from_local_datetime(&naive).latest().unwrap(),
+        ] {
+            let easy_time = EasyTime::new_with_time(0, time);
+            assert_eq!(
+                easy_time.with_millisecond(0),
+                Ok(time - Duration::milliseconds(500))
+            );
+            assert_eq!(
+                easy_time.with_millisecond(250),
+                Ok(time - Duration::milliseconds(250))
+            );
+        }
+    }
+
+    // Test start_of_minute and end_of_minute containing a time in a repeated hour
+    #[test]
+    fn test_minute_bounds_ambiguous_hour() {
+        let new_york = chrono_tz::America::New_York;
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 11, 5)
+            .unwrap()
+            .and_hms_milli_opt(1, 30, 0, 500)
+            .unwrap();
+        let time = new_york.from_local_datetime(&naive).latest().unwrap();
+        let easy_time = EasyTime::new_with_time(0, time);
+        let (start, end) = (easy_time.start_of_minute(), easy_time.end_of_minute());
+        assert_eq!(time - start, Duration::milliseconds(500));
+        assert!(start <= time && time <= end);
+        assert_eq!(end - start, Duration::minutes(1) - Duration::nanoseconds(1));
+    }
+
+    // Test start_of_hour and end_of_hour keeping the offset of a repeated hour
+    #[test]
+    fn test_hour_bounds_ambiguous_hour() {
+        let new_york = chrono_tz::America::New_York;
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 11, 5)
+            .unwrap()
+            .and_hms_opt(1, 30, 0)
+            .unwrap();
+        for time in [
+            new_york.from_local_datetime(&naive).earliest().unwrap(),
+            new_york.from_local_datetime(&naive).latest().unwrap(),
+        ] {
+            let easy_time = EasyTime::new_with_time(0, time);
+            let (start, end) = (easy_time.start_of_hour(), easy_time.end_of_hour());
+            assert_eq!(time - start, Duration::minutes(30));
+            assert!(start <= time && time <= end);
+            assert_eq!(start, easy_time.truncate(TimeUnits::Hours));
+            assert_eq!(start.offset(), time.offset());
+        }
+    }
+
+    // Test truncate and round below a day keeping the offset of a repeated hour
+    #[test]
+    fn test_truncate_and_round_ambiguous_hour() {
+        let new_york = chrono_tz::America::New_York;
+        let naive = chrono::NaiveDate::from_ymd_opt(2023, 11, 5)
+            .unwrap()
+            .and_hms_milli_opt(1, 30, 40, 500)
+            .unwrap();
+        let time = new_york.from_local_datetime(&naive).latest().unwrap();
+        let easy_time = EasyTime::new_with_time(0, time);
+        assert_eq!(
+            time - easy_time.truncate(TimeUnits::Seconds),
+            Duration::milliseconds(500)
+        );
+        assert_eq!(
+            easy_time.round(TimeUnits::Minutes) - time,
+            Duration::milliseconds(19_500)
+        );
+        assert_eq!(
+            easy_time.round(TimeUnits::Hours) - time,
+            Duration::milliseconds(29 * 60_000 + 19_500)
+        );
+    }
+
+    // Test week_bounds containing a time before a repeated midnight (Santiago, 2023-04-01)
+    #[test]
+    fn test_week_bounds_ambiguous_end() {
+        let santiago = chrono_tz::America::Santiago;
+        let time = santiago
+            .with_ymd_and_hms(2023, 4, 1, 23, 30, 0)
+            .latest()
+            .unwrap();
+        let easy_time = EasyTime::new_with_time(0, time);
+        let (start, end) = easy_time.week_bounds(chrono::Weekday::Sun);
+        assert!(start <= time && time <= end);
+        assert_eq!(end, easy_time.end_of_day());
+        assert_eq!(
+            chrono::Offset::fix(end.offset()).local_minus_utc(),
+            -4 * 3600
+        );
+    }
+
+    // Test millenniums_from_now saturating west of UTC where the clamped year ends past chrono's range
+    #[test]
+    fn test_millenniums_saturate_west_offset() {
+        let west = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        let time = west.with_ymd_and_hms(2023, 12, 31, 23, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(1_000_000, time);
+        assert_eq!(
+            easy_time.millenniums_from_now(),
+            DateTime::<Utc>::MAX_UTC.with_timezone(&west)
+        );
+    }
+
+    // Test millenniums_ago saturating east of UTC where the clamped year starts before chrono's range
+    #[test]
+    fn test_millenniums_saturate_east_offset() {
+        let east = chrono::FixedOffset::east_opt(5 * 3600).unwrap();
+        let time = east.with_ymd_and_hms(2023, 1, 1, 1, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(1_000_000, time);
+        assert_eq!(
+            easy_time.millenniums_ago(),
+            DateTime::<Utc>::MIN_UTC.with_timezone(&east)
+        );
+    }
+
+    // Test set_value_checked rejecting years whose result the offset pushes out of range
+    #[test]
+    fn test_set_value_checked_rejects_years_past_offset_range() {
+        let west = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        let time = west.with_ymd_and_hms(2023, 12, 31, 23, 0, 0).unwrap();
+        let mut easy_time = EasyTime::new_with_time(0, time);
+        let value = chrono::NaiveDate::MAX.year() as i64 - 2023;
+        assert_eq!(
+            easy_time.set_value_checked(value, TimeUnits::Years),
+            Err(EasyTimeError::ValueOverflow {
+                value,
+                unit: TimeUnits::Years
+            })
+        );
+        assert!(easy_time
+            .set_value_checked(value - 1, TimeUnits::Years)
+            .is_ok());
+        assert_eq!(
+            easy_time.years_from_now().year(),
+            chrono::NaiveDate::MAX.year() - 1
+        );
+    }
+
+    // Test try_quarters_ago reporting an overflow for i32::MIN instead of panicking
+    #[test]
+    fn test_try_quarters_ago_i32_min() {
+        let easy_time = EasyTime::new_with_time(i32::MIN as i64, Utc::now());
+        assert!(matches!(
+            easy_time.try_quarters_ago(),
+            Err(EasyTimeError::ValueOverflow { .. })
+        ));
+    }
+
+    // Test dates_until stepping over a spring-forward day (New York, 2023-03-12)
+    #[test]
+    fn test_dates_until_spring_forward() {
+        let new_york = chrono_tz::America::New_York;
+        let start = new_york.with_ymd_and_hms(2023, 3, 10, 2, 30, 0).unwrap();
+        let target = new_york.with_ymd_and_hms(2023, 3, 14, 0, 0, 0).unwrap();
+        let dates: Vec<_> = EasyTime::new_with_time(0, start)
+            .dates_until(target)
+            .collect();
+        assert_eq!(dates.len(), 5);
+        assert_eq!(
+            dates[2],
+            new_york.with_ymd_and_hms(2023, 3, 12, 3, 0, 0).unwrap()
+        );
+        assert_eq!(
+            dates[3],
+            new_york.with_ymd_and_hms(2023, 3, 13, 2, 30, 0).unwrap()
+        );
+    }
+
+    // Test every fixed-length offset saturating like fortnights
+    #[test]
+    fn test_fixed_offsets_saturate() {
+        let date_time = Utc.with_ymd_and_hms(2023, 6, 2, 9, 0, 0).unwrap();
+        let easy_time = EasyTime::new_with_time(i64::MAX, date_time);
+        assert_eq!(easy_time.weeks_from_now(), DateTime::<Utc>::MAX_UTC);
+        assert_eq!(easy_time.days_ago(), DateTime::<Utc>::MIN_UTC);
+        assert_eq!(easy_time.seconds_from_now(), DateTime::<Utc>::MAX_UTC);
+        assert_eq!(easy_time.milliseconds_ago(), DateTime::<Utc>::MIN_UTC);
+        assert_eq!(
+            easy_time.in_future(TimeUnits::Hours),
+            DateTime::<Utc>::MAX_UTC
+        );
+        assert_eq!(
+            easy_time.in_past(TimeUnits::Minutes),
+            DateTime::<Utc>::MIN_UTC
+        );
+        let negative = EasyTime::new_with_time(i64::MIN, date_time);
+        assert_eq!(negative.weeks_from_now(), DateTime::<Utc>::MIN_UTC);
+        assert_eq!(negative.weeks_ago(), DateTime::<Utc>::MAX_UTC);
+    }
 }